@@ -1,16 +1,23 @@
-use crate::bootstrap::helper::read_positive_integers_from_file;
-use crate::bootstrap::meta::{combinations_maker_wrapper, one_iteration, reduce_meta};
-use crate::bootstrap::reader::read_meta;
-use crate::bootstrap::writer::{write_meta, write_output};
-use crate::helpers::helper::calc_similarity;
+use crate::bootstrap::helper::{random_seed, read_positive_integers_from_file};
+use crate::bootstrap::meta::{
+    bootstrap_curves, bootstrap_items, bootstrap_summary, combination_counts,
+    combinations_maker_wrapper, group_curves, one_iteration, ordered_growth, presence_items,
+    reduce_meta, stratified_combinations,
+};
+use crate::bootstrap::reader::{read_meta, read_order};
+use crate::bootstrap::writer::{
+    write_combinations, write_group_curves, write_growth, write_meta, write_output, write_summary,
+};
+use crate::helpers::heaps::heaps_law;
+use crate::helpers::region::{read_bed, region_nodes, restrict_graph};
+use crate::rarefaction::writer::write_heaps;
 use clap::ArgMatches;
-use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
+use gfa_reader::{check_numeric_gfafile, Gfa, Pansn, Path};
 use rayon::prelude::*;
-use std::cmp::min;
 use std::collections::HashSet;
 
 /// Main function for bootstrapping
-pub fn bootstrap_main(matches: &ArgMatches) {
+pub fn bootstrap_main(matches: &ArgMatches, regions: &[(String, u64, u64)], output: &str) {
     let mut sep = " ";
     if matches.is_present("Pan-SN") {
         sep = matches.value_of("Pan-SN").unwrap();
@@ -26,18 +33,39 @@ pub fn bootstrap_main(matches: &ArgMatches) {
         // Read the graph
         let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
         graph.walk_to_path(sep);
+        if !regions.is_empty() {
+            restrict_graph(&mut graph, regions);
+        }
 
         if graph.paths.is_empty() {
             panic!("Error: No path found in graph file")
         } else {
             let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
-            let output = matches.value_of("output").unwrap();
 
             let mut nodes: HashSet<_> = graph.segments.iter().map(|n| n.id).collect();
             if matches.is_present("nodes") {
                 let a = read_positive_integers_from_file(matches.value_of("nodes").unwrap());
                 nodes = a.iter().cloned().collect();
             }
+            // Nodes which overlap the BED regions (on any path, e.g. a reference)
+            if matches.is_present("bed") {
+                let bed_nodes = region_nodes(&graph, &read_bed(matches.value_of("bed").unwrap()));
+                if matches.is_present("nodes") {
+                    nodes.retain(|x| bed_nodes.contains(x));
+                } else {
+                    nodes = bed_nodes;
+                }
+            }
+            eprintln!("Nodes: {}", nodes.len());
+
+            let metric = matches.value_of("metric").unwrap_or("similarity");
+            eprintln!("Metric: {}", metric);
+
+            // Deterministic growth curve (user-defined order) instead of random combinations
+            if matches.is_present("order") {
+                ordered_main(matches, &wrapper, &graph, &nodes, metric, threads, output);
+                return;
+            }
 
             // Get the amount of iterations
             let mut amount = 10;
@@ -45,15 +73,54 @@ pub fn bootstrap_main(matches: &ArgMatches) {
                 amount = matches.value_of("number").unwrap().parse().unwrap();
             }
 
-            // Limit the amount of iterations (maximum 500)
-            amount = min(amount, 500);
+            // Use all combinations if there are not more than this
+            let mut exhaustive = amount;
+            if matches.is_present("exhaustive") {
+                exhaustive = matches
+                    .value_of("exhaustive")
+                    .unwrap()
+                    .parse()
+                    .expect("Error: Exhaustive threshold is not a positive integer");
+            }
+
+            // Sample groups for a stratified bootstrap: [group, samples (index)]
+            let mut groups = Vec::new();
+            if matches.is_present("groups") {
+                groups = group_samples(
+                    &read_order(matches.value_of("groups").unwrap()),
+                    &wrapper.get_path_genome(),
+                );
+            }
+            let equal = match matches.value_of("strata") {
+                None | Some("proportional") => false,
+                Some("equal") => true,
+                Some(x) => panic!("Error: Unknown stratification {}", x),
+            };
 
             // Combination: {number of genomes, number of iteration, combination (HashSet)}
             let mut combinations: Vec<(usize, usize, HashSet<usize>)>;
+            let mut seed = None;
+            // Possible combinations of the stratified bootstrap
+            let mut possible = Vec::new();
             if matches.is_present("meta input") {
                 combinations = read_meta(matches.value_of("meta input").unwrap());
             } else {
-                combinations = combinations_maker_wrapper(&wrapper.genomes.len(), &amount);
+                let s = match matches.value_of("seed") {
+                    Some(x) => x.parse().expect("Error: Seed is not a positive integer"),
+                    None => random_seed(),
+                };
+                eprintln!("Seed: {}", s);
+                seed = Some(s);
+                if groups.is_empty() {
+                    combinations =
+                        combinations_maker_wrapper(&wrapper.genomes.len(), &amount, &exhaustive, s);
+                } else {
+                    eprintln!("Stratified bootstrap ({} groups)", groups.len());
+                    let stratified =
+                        stratified_combinations(&groups, &amount, &exhaustive, equal, s);
+                    combinations = stratified.0;
+                    possible = stratified.1;
+                }
             }
 
             // Which line should be read
@@ -79,10 +146,22 @@ pub fn bootstrap_main(matches: &ArgMatches) {
             // Removes lines and unused similarity level from the meta data (file)
             reduce_meta(&mut combinations, line, core);
 
+            // Number of distinct combinations for each number of genomes
+            let mut counts = combination_counts(&combinations, wrapper.genomes.len());
+            for count in counts.iter_mut() {
+                if let Some(x) = possible.iter().find(|x| x.0 == count.0) {
+                    count.1 = x.1;
+                }
+            }
+            for (number, possible, used) in counts.iter() {
+                eprintln!("Size {}: {} of {} combinations", number, used, possible);
+            }
+            write_combinations(&counts, &(output.to_string() + ".combinations.txt"));
+
             let paths = wrapper.get_path_genome();
 
-            // We use the similarity measure
-            let similarity = calc_similarity(&paths, &graph);
+            // Nodes or edges of each sample
+            let (items, sizes, subset) = bootstrap_items(metric, &paths, &graph, &nodes);
 
             let thread_pool = rayon::ThreadPoolBuilder::new()
                 .num_threads(threads)
@@ -97,14 +176,8 @@ pub fn bootstrap_main(matches: &ArgMatches) {
                             .iter()
                             .map(|(number_genomes, iterations, combination)| {
                                 let combi: Vec<usize> = combination.iter().cloned().collect();
-                                let result_one_iteration = one_iteration(
-                                    &wrapper,
-                                    &graph,
-                                    &combi,
-                                    "similarity",
-                                    &similarity,
-                                    &nodes,
-                                );
+                                let result_one_iteration =
+                                    one_iteration(&combi, &items, &sizes, &subset);
 
                                 // Return results without a semicolon
                                 (
@@ -140,14 +213,141 @@ pub fn bootstrap_main(matches: &ArgMatches) {
             //     metas.push((*number_genomes, *iterations, combination.clone()));
             // }
 
+            // Depth metrics: levels are traversal counts, summary and curves are based on the number of samples
+            let depth = metric.ends_with("depth");
+            let edges = metric.starts_with("edge");
+            let distinct = if depth {
+                presence_items(&items)
+            } else {
+                Vec::new()
+            };
+            let presence = if depth { &distinct } else { &items };
+            let sample_total: Vec<_> = if depth {
+                thread_pool.install(|| {
+                    metas
+                        .par_iter()
+                        .map(|(number_genomes, iterations, combination)| {
+                            let combi: Vec<usize> = combination.iter().cloned().collect();
+                            (
+                                *number_genomes,
+                                *iterations,
+                                one_iteration(&combi, presence, &sizes, &subset),
+                            )
+                        })
+                        .collect()
+                })
+            } else {
+                total.clone()
+            };
+
             // Write the meta data if wanted
-            let metas_output = matches.value_of("output").unwrap().to_string() + ".meta";
-            write_meta(metas, &metas_output);
+            let metas_output = output.to_string() + ".meta";
+            write_meta(metas, &metas_output, seed);
+
+            // Summary statistics for each number of genomes
+            write_summary(
+                &bootstrap_summary(&sample_total, edges),
+                &(output.to_string() + ".summary.txt"),
+            );
+
+            // Heaps' law on the mean pan and core size
+            let curves = bootstrap_curves(&sample_total);
+            let mut heaps = heaps_law(&curves);
+            if edges {
+                heaps.retain(|x| x.1 != "[bp]");
+                heaps.iter_mut().for_each(|x| x.1 = "#Edge".to_string());
+            }
+            write_heaps(&heaps, &(output.to_string() + ".heaps.txt"));
+
+            // Growth curves of each group and the stratified total
+            // (not with a meta file as input)
+            if let Some(s) = seed.filter(|_| !groups.is_empty()) {
+                // Each group has its own seed
+                let group_combinations: Vec<_> = groups
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (_, samples))| {
+                        combinations_maker_wrapper(
+                            &samples.len(),
+                            &amount,
+                            &exhaustive,
+                            s.wrapping_add(i as u64 + 1),
+                        )
+                    })
+                    .collect();
+                let mut data = thread_pool.install(|| {
+                    group_curves(&groups, &group_combinations, presence, &sizes, &subset)
+                });
+                data.push(("Stratified".to_string(), curves));
+                write_group_curves(&data, &(output.to_string() + ".groups.txt"), edges);
+            }
 
             // Write the main output
-            write_output(total, output);
+            write_output(total, output, edges);
         }
     } else {
         panic!("GFA file is not numeric");
     }
 }
+
+/// Cumulative growth in a user-defined order
+///
+/// Samples (or groups of samples) are added one after another, each step is one combination of all samples added so far
+fn ordered_main(
+    matches: &ArgMatches,
+    wrapper: &Pansn<u32, (), ()>,
+    graph: &Gfa<u32, (), ()>,
+    nodes: &HashSet<u32>,
+    metric: &str,
+    threads: usize,
+    output: &str,
+) {
+    let paths = wrapper.get_path_genome();
+
+    // Steps: [group name, samples (index)] in order of first appearance
+    let steps = group_samples(&read_order(matches.value_of("order").unwrap()), &paths);
+    eprintln!("Running ordered growth ({} steps)", steps.len());
+
+    // Core and growth are based on the number of samples (also for the depth metrics)
+    let (items, sizes, subset) = bootstrap_items(metric, &paths, graph, nodes);
+    let items = presence_items(&items);
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let growth = thread_pool.install(|| ordered_growth(&steps, &items, &sizes, &subset));
+
+    write_growth(&growth, output, metric.starts_with("edge"));
+}
+
+/// Group samples (index) by the second column of the sample table
+///
+/// Groups are in order of their first appearance, samples which are not in the table are not used
+fn group_samples(
+    table: &[(String, String)],
+    paths: &[(String, Vec<&Path<u32, (), ()>>)],
+) -> Vec<(String, Vec<usize>)> {
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for (sample, group) in table.iter() {
+        let index = paths
+            .iter()
+            .position(|x| &x.0 == sample)
+            .unwrap_or_else(|| panic!("Error: Sample {} not found in graph file", sample));
+        match groups.iter_mut().find(|x| &x.0 == group) {
+            Some(g) => {
+                if !g.1.contains(&index) {
+                    g.1.push(index)
+                }
+            }
+            None => groups.push((group.to_string(), vec![index])),
+        }
+    }
+    if groups.is_empty() {
+        panic!("Error: Sample table is empty")
+    }
+    let used: usize = groups.iter().map(|x| x.1.len()).sum();
+    if used < paths.len() {
+        eprintln!("{} samples are not in the sample table", paths.len() - used);
+    }
+    groups
+}
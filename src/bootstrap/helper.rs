@@ -1,4 +1,5 @@
-use rand::{seq::IteratorRandom, thread_rng}; // 0.6.1
+use rand::rngs::StdRng;
+use rand::{seq::IteratorRandom, thread_rng, Rng}; // 0.6.1
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::vec::Vec;
@@ -6,16 +7,21 @@ use std::vec::Vec;
 /// Create a a vector of n (number) random numbers
 /// size = Random number between 0 and size
 /// number = amount of numbers you want to draw
-pub fn random_numbers(size: &usize, number: &usize) -> Vec<usize> {
-    let mut rng = thread_rng();
+/// rng = seeded random number generator (reproducible)
+pub fn random_numbers(size: &usize, number: &usize, rng: &mut StdRng) -> Vec<usize> {
     let v: Vec<usize> = (0..*size).collect();
-    let sample: Vec<&usize> = v.iter().choose_multiple(&mut rng, *number);
+    let sample: Vec<&usize> = v.iter().choose_multiple(rng, *number);
 
     // Clone (so no reference)
     let sample: Vec<usize> = sample.iter().copied().cloned().collect();
     sample
 }
 
+/// Random seed (if the user does not provide one)
+pub fn random_seed() -> u64 {
+    thread_rng().gen()
+}
+
 pub fn read_positive_integers_from_file(filename: &str) -> Vec<u32> {
     let file = File::open(filename).expect("Failed to open file");
     let reader = io::BufReader::new(file);
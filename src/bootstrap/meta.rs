@@ -1,18 +1,33 @@
 use crate::bootstrap::helper::random_numbers;
-use gfa_reader::{Gfa, Pansn, Path};
-use std::collections::HashSet;
+use crate::helpers::helper::{
+    calc_node_len, mean, median, path_edges, percentile, standard_deviation,
+};
+use gfa_reader::{Gfa, Path};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 /// Wrapper for combinations
 ///
 /// Creates meta data for the bootstrap
 /// One entry consists of: [number of genomes, number of iteration, combination (HashSet)]
+/// All combinations are used if there are at most 'exhaustive' of them, otherwise 'amount' distinct combinations are drawn
+/// The same seed always returns the same combinations
 pub fn combinations_maker_wrapper(
     size: &usize,
     amount: &usize,
+    exhaustive: &usize,
+    seed: u64,
 ) -> Vec<(usize, usize, HashSet<usize>)> {
+    let mut rng = StdRng::seed_from_u64(seed);
     let mut data = vec![];
     for number in 2..size + 1 {
-        let test_comb = combinations_maker(size, &number, amount);
+        let test_comb = if binomial(*size, number) <= *exhaustive as u128 {
+            all_combinations(size, &number)
+        } else {
+            combinations_maker(size, &number, amount, &mut rng)
+        };
         for (run, combination) in test_comb.iter().enumerate() {
             data.push((number, run, combination.clone()))
         }
@@ -20,30 +35,273 @@ pub fn combinations_maker_wrapper(
     data
 }
 
-/// Makes multiple random combinations
+/// Makes multiple random combinations (uniform, without replacement)
 /// size = total sample size [0,1,2,3,4,5,6,7,8,9]
 /// number = size of the sample (3 -> [1,2,3])
 /// amount = amount og samples that should be drawn (2 -> [[1,2,3], [4,5,6]])
 ///
-/// TODO
-/// Make a check when not more different samples can be drawn (very unlikely)
+/// If there are not more than 'amount' different combinations, all of them are returned
 pub fn combinations_maker(
     size: &usize,
     core_number: &usize,
     amount: &usize,
+    rng: &mut StdRng,
 ) -> Vec<HashSet<usize>> {
+    if binomial(*size, *core_number) <= *amount as u128 {
+        return all_combinations(size, core_number);
+    }
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
     let mut result = Vec::new();
-    let mut counter = 0;
     while result.len() != *amount {
-        let v = random_numbers(size, core_number);
-        let v: HashSet<usize> = v.iter().cloned().collect();
-        if !result.contains(&v) {
-            result.push(v);
+        let mut v = random_numbers(size, core_number, rng);
+        v.sort();
+        if seen.insert(v.clone()) {
+            result.push(v.into_iter().collect());
         }
-        if counter > 10000 {
+    }
+    result
+}
+
+/// All combinations of 'number' out of 'size' samples (lexicographic order)
+pub fn all_combinations(size: &usize, number: &usize) -> Vec<HashSet<usize>> {
+    let mut result = Vec::new();
+    let mut combination: Vec<usize> = (0..*number).collect();
+    loop {
+        result.push(combination.iter().cloned().collect());
+        // Rightmost position which can be increased
+        let mut i = *number;
+        while i > 0 && combination[i - 1] == size - number + i - 1 {
+            i -= 1;
+        }
+        if i == 0 {
             return result;
         }
-        counter += 1;
+        combination[i - 1] += 1;
+        for j in i..*number {
+            combination[j] = combination[j - 1] + 1;
+        }
+    }
+}
+
+/// Binomial coefficient C(n, k), saturating at u128::MAX
+pub fn binomial(n: usize, k: usize) -> u128 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        // Exact: result * (n - i) is always divisible by (i + 1)
+        result = match result.checked_mul((n - i) as u128) {
+            Some(x) => x / (i + 1) as u128,
+            None => return u128::MAX,
+        };
+    }
+    result
+}
+
+/// Number of possible and distinct used combinations for each number of genomes
+///
+/// Output is a vector of [number of genomes, possible combinations, used combinations]
+pub fn combination_counts(
+    meta: &[(usize, usize, HashSet<usize>)],
+    size: usize,
+) -> Vec<(usize, u128, usize)> {
+    let mut sizes: Vec<usize> = meta.iter().map(|x| x.0).collect();
+    sizes.sort();
+    sizes.dedup();
+    sizes
+        .iter()
+        .map(|number| {
+            let distinct: HashSet<Vec<usize>> = meta
+                .iter()
+                .filter(|x| x.0 == *number)
+                .map(|x| {
+                    let mut v: Vec<usize> = x.2.iter().cloned().collect();
+                    v.sort();
+                    v
+                })
+                .collect();
+            (*number, binomial(size, *number), distinct.len())
+        })
+        .collect()
+}
+
+/// Stratified combinations
+///
+/// groups = [group name, samples (index)]
+/// Each combination contains a fixed number of samples from each group (see stratified_allocation), samples are drawn randomly within each group
+/// Output: combinations (same format as combinations_maker_wrapper) and the number of possible combinations for each number of genomes
+pub fn stratified_combinations(
+    groups: &[(String, Vec<usize>)],
+    amount: &usize,
+    exhaustive: &usize,
+    equal: bool,
+    seed: u64,
+) -> (Vec<(usize, usize, HashSet<usize>)>, Vec<(usize, u128)>) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let sizes: Vec<usize> = groups.iter().map(|x| x.1.len()).collect();
+    let mut data = Vec::new();
+    let mut possible = Vec::new();
+    for allocation in stratified_allocation(&sizes, equal).iter() {
+        let number: usize = allocation.iter().sum();
+        let total = allocation
+            .iter()
+            .zip(sizes.iter())
+            .fold(1u128, |acc, (k, n)| acc.saturating_mul(binomial(*n, *k)));
+        possible.push((number, total));
+
+        let mut combinations: Vec<HashSet<usize>> = Vec::new();
+        if total <= *exhaustive as u128 || total <= *amount as u128 {
+            // Cartesian product of all combinations of each group
+            combinations.push(HashSet::new());
+            for ((_, samples), k) in groups.iter().zip(allocation.iter()) {
+                let group_combinations = all_combinations(&samples.len(), k);
+                let mut extended = Vec::new();
+                for combination in combinations.iter() {
+                    for group_combination in group_combinations.iter() {
+                        let mut c = combination.clone();
+                        c.extend(group_combination.iter().map(|x| samples[*x]));
+                        extended.push(c);
+                    }
+                }
+                combinations = extended;
+            }
+        } else {
+            let mut seen: HashSet<Vec<usize>> = HashSet::new();
+            while combinations.len() != *amount {
+                let mut v: Vec<usize> = Vec::new();
+                for ((_, samples), k) in groups.iter().zip(allocation.iter()) {
+                    v.extend(
+                        random_numbers(&samples.len(), k, &mut rng)
+                            .iter()
+                            .map(|x| samples[*x]),
+                    );
+                }
+                v.sort();
+                if seen.insert(v.clone()) {
+                    combinations.push(v.into_iter().collect());
+                }
+            }
+        }
+        for (run, combination) in combinations.into_iter().enumerate() {
+            data.push((number, run, combination));
+        }
+    }
+    (data, possible)
+}
+
+/// Number of samples drawn from each group for each number of genomes
+///
+/// Proportional: 2..=n genomes, each group contributes according to its size (largest remainder, ties by group order)
+/// Equal: the same number of samples (1..=smallest group) from each group
+pub fn stratified_allocation(sizes: &[usize], equal: bool) -> Vec<Vec<usize>> {
+    let mut result = Vec::new();
+    if equal {
+        let min_size = *sizes.iter().min().unwrap();
+        for m in 1..=min_size {
+            if m * sizes.len() >= 2 {
+                result.push(vec![m; sizes.len()]);
+            }
+        }
+    } else {
+        let n: usize = sizes.iter().sum();
+        for k in 2..=n {
+            let mut allocation: Vec<usize> = sizes.iter().map(|x| k * x / n).collect();
+            let mut remainders: Vec<(usize, usize)> = sizes
+                .iter()
+                .enumerate()
+                .map(|(i, x)| (k * x % n, i))
+                .collect();
+            // Largest remainder first, ties by group order
+            remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+            let missing = k - allocation.iter().sum::<usize>();
+            for (_, i) in remainders.iter().take(missing) {
+                allocation[*i] += 1;
+            }
+            result.push(allocation);
+        }
+    }
+    result
+}
+
+/// Bootstrap within each group
+///
+/// combinations: combinations of each group (index within the group)
+/// Output: [group name, mean curves (see bootstrap_curves)]
+pub fn group_curves(
+    groups: &[(String, Vec<usize>)],
+    combinations: &[Vec<(usize, usize, HashSet<usize>)>],
+    items: &[Vec<u32>],
+    sizes: &[usize],
+    subset: &[bool],
+) -> Vec<(String, Vec<(usize, f64, f64, f64, f64)>)> {
+    let mut result = Vec::new();
+    for ((group, samples), combinations) in groups.iter().zip(combinations.iter()) {
+        let total: Vec<(usize, usize, (Vec<usize>, Vec<usize>))> = combinations
+            .par_iter()
+            .map(|(number, run, combination)| {
+                let combi: Vec<usize> = combination.iter().map(|x| samples[*x]).collect();
+                (*number, *run, one_iteration(&combi, items, sizes, subset))
+            })
+            .collect();
+        result.push((group.to_string(), bootstrap_curves(&total)));
+    }
+    result
+}
+
+/// Cumulative growth for ordered steps
+///
+/// Each step adds one or multiple samples (index)
+/// Output is a vector of [group, number of genomes, pan (nodes, bp), core (nodes, bp), new (nodes, bp)]
+pub fn ordered_growth(
+    steps: &[(String, Vec<usize>)],
+    items: &[Vec<u32>],
+    sizes: &[usize],
+    subset: &[bool],
+) -> Vec<(
+    String,
+    usize,
+    (usize, usize),
+    (usize, usize),
+    (usize, usize),
+)> {
+    let mut combinations: Vec<Vec<usize>> = Vec::new();
+    let mut combination: Vec<usize> = Vec::new();
+    for (_, samples) in steps.iter() {
+        combination.extend(
+            samples
+                .iter()
+                .filter(|x| !combination.contains(x))
+                .cloned()
+                .collect::<Vec<usize>>(),
+        );
+        combinations.push(combination.clone());
+    }
+
+    let levels: Vec<(Vec<usize>, Vec<usize>)> = combinations
+        .par_iter()
+        .map(|combi| one_iteration(combi, items, sizes, subset))
+        .collect();
+
+    let mut result = Vec::new();
+    let mut last = (0, 0);
+    for ((group, _), (combi, (level_nodes, level_seq))) in
+        steps.iter().zip(combinations.iter().zip(levels.iter()))
+    {
+        let pan = (level_nodes.iter().sum(), level_seq.iter().sum());
+        let core = (
+            *level_nodes.get(combi.len() - 1).unwrap_or(&0),
+            *level_seq.get(combi.len() - 1).unwrap_or(&0),
+        );
+        result.push((
+            group.to_string(),
+            combi.len(),
+            pan,
+            core,
+            (pan.0 - last.0, pan.1 - last.1),
+        ));
+        last = pan;
     }
     result
 }
@@ -58,38 +316,106 @@ pub fn reduce_meta(meta: &mut Vec<(usize, usize, HashSet<usize>)>, line: i32, co
     }
 }
 
+/// Items (nodes or edges) of each sample for a bootstrap metric
+///
+/// - similarity: distinct nodes of each sample (level = number of samples)
+/// - depth: all node traversals of each sample (level = number of traversals)
+/// - edge: distinct edges of each sample (level = number of samples)
+/// - edge-depth: all edge traversals of each sample (level = number of traversals)
+///
+/// Output: [items of each sample, sequence of each item [bp] (0 for edges), item is in the node subset]
+/// Edges are in the subset if both nodes are.
+pub fn bootstrap_items(
+    metric: &str,
+    paths: &[(String, Vec<&Path<u32, (), ()>>)],
+    graph: &Gfa<u32, (), ()>,
+    nodes: &HashSet<u32>,
+) -> (Vec<Vec<u32>>, Vec<usize>, Vec<bool>) {
+    let mut items = Vec::new();
+    match metric {
+        "similarity" | "depth" => {
+            for (_, p) in paths.iter() {
+                let mut sample: Vec<u32> = p.iter().flat_map(|x| x.nodes.iter()).cloned().collect();
+                if metric == "similarity" {
+                    sample.sort();
+                    sample.dedup();
+                }
+                items.push(sample);
+            }
+            let sizes = calc_node_len(graph).iter().map(|x| *x as usize).collect();
+            let subset = (0..=graph.segments.iter().max().unwrap().id)
+                .map(|x| nodes.contains(&x))
+                .collect();
+            (items, sizes, subset)
+        }
+        "edge" | "edge-depth" => {
+            // Index of each edge
+            let mut index: HashMap<u64, u32> = HashMap::new();
+            let mut subset = Vec::new();
+            for (_, p) in paths.iter() {
+                let mut sample = Vec::new();
+                for path in p.iter() {
+                    for (edge, n) in path_edges(path).iter().zip(path.nodes.windows(2)) {
+                        let i = *index.entry(*edge).or_insert_with(|| {
+                            subset.push(nodes.contains(&n[0]) && nodes.contains(&n[1]));
+                            subset.len() as u32 - 1
+                        });
+                        sample.push(i);
+                    }
+                }
+                if metric == "edge" {
+                    sample.sort();
+                    sample.dedup();
+                }
+                items.push(sample);
+            }
+            (items, vec![0; subset.len()], subset)
+        }
+        _ => panic!("Error: Unknown metric {}", metric),
+    }
+}
+
+/// Distinct items of each sample
+///
+/// For the depth metrics (levels are traversal counts): core, summary and growth curves are based on the number of samples
+pub fn presence_items(items: &[Vec<u32>]) -> Vec<Vec<u32>> {
+    items
+        .iter()
+        .map(|x| {
+            let mut sample = x.clone();
+            sample.sort();
+            sample.dedup();
+            sample
+        })
+        .collect()
+}
+
 /// Calculation for one iteration
 ///
-/// Take core and then remove stuff from it
+/// Count the items of all samples in the combination
+/// Output: number of items and sequence [bp] for each level (1..=max level)
 pub fn one_iteration(
-    gw: &Pansn<u32, (), ()>,
-    graph: &Gfa<u32, (), ()>,
     combination: &[usize],
-    _metric: &str,
-    information: &Vec<u32>,
-    nodes: &HashSet<u32>,
+    items: &[Vec<u32>],
+    sizes: &[usize],
+    subset: &[bool],
 ) -> (Vec<usize>, Vec<usize>) {
-    let paths = gw.get_path_genome();
-    let info2 = test1(&paths, graph, information, combination);
-    let max_value = *info2.iter().max().unwrap();
+    let mut counts: Vec<u32> = vec![0; sizes.len()];
+    for sample in combination.iter() {
+        for item in items[*sample].iter() {
+            counts[*item as usize] += 1;
+        }
+    }
+    let max_value = *counts.iter().max().unwrap_or(&0);
 
     let mut result: Vec<usize> = vec![0; max_value as usize + 1]; // NODES
     let mut result2 = vec![0; max_value as usize + 1]; // Sequence
 
     // Add amount and sequence
-    if nodes.len() == graph.segments.len() {
-        for (i, x) in info2.iter().enumerate() {
-            if *x != 0 {
-                result[*x as usize] += 1;
-                result2[*x as usize] += graph.get_node_by_id(&(i as u32)).sequence.get_len();
-            }
-        }
-    } else {
-        for (i, x) in info2.iter().enumerate() {
-            if nodes.contains(&(i as u32 + 1)) && *x != 0 {
-                result[*x as usize] += 1;
-                result2[*x as usize] += graph.get_node_by_id(&(i as u32)).sequence.get_len();
-            }
+    for (i, x) in counts.iter().enumerate() {
+        if *x != 0 && subset[i] {
+            result[*x as usize] += 1;
+            result2[*x as usize] += sizes[i];
         }
     }
     result2.remove(0);
@@ -98,47 +424,90 @@ pub fn one_iteration(
     (result, result2)
 }
 
-/// Reduce vector wrapper
-/// - Iterate over the genome with one combination
-/// - Check if the genome is in the combination
-/// - If not, remove it from the vector
-pub fn test1(
-    wrapper: &Vec<(String, Vec<&Path<u32, (), ()>>)>,
-    graph: &Gfa<u32, (), ()>,
-    info: &Vec<u32>,
-    comb: &[usize],
-) -> Vec<u32> {
-    let mut info2 = info.clone();
-    for (i, x) in wrapper.iter().enumerate() {
-        if !comb.contains(&i) {
-            let a = make_vec(&x.1, graph.segments.iter().max().unwrap().id as usize);
-            remove_from_vec(&mut info2, &a);
+/// Mean pan and core size for each number of genomes
+///
+/// Input: output of all iterations [number of genomes, iteration, (nodes, sequence) for each similarity level]
+/// Output: [number of genomes, pan nodes, pan bp, core nodes, core bp], sorted by number of genomes
+pub fn bootstrap_curves(
+    data: &[(usize, usize, (Vec<usize>, Vec<usize>))],
+) -> Vec<(usize, f64, f64, f64, f64)> {
+    let mut sizes: Vec<usize> = data.iter().map(|x| x.0).collect();
+    sizes.sort();
+    sizes.dedup();
+    let mut result = Vec::new();
+    for size in sizes.iter() {
+        let runs: Vec<&(Vec<usize>, Vec<usize>)> =
+            data.iter().filter(|x| x.0 == *size).map(|x| &x.2).collect();
+        let count = runs.len() as f64;
+        let mut curve = (*size, 0.0, 0.0, 0.0, 0.0);
+        for (nodes, seq) in runs.iter() {
+            curve.1 += nodes.iter().sum::<usize>() as f64 / count;
+            curve.2 += seq.iter().sum::<usize>() as f64 / count;
+            curve.3 += *nodes.get(size - 1).unwrap_or(&0) as f64 / count;
+            curve.4 += *seq.get(size - 1).unwrap_or(&0) as f64 / count;
         }
+        result.push(curve);
     }
-    info2
+    result
 }
 
-/// For the subset of path get all vectors covered
+/// Summary statistics over all iterations for each number of genomes
 ///
-/// Create a vector of node_length with only contains 1 if the node is in one of the paths
-pub fn make_vec(t: &Vec<&Path<u32, (), ()>>, length: usize) -> Vec<u32> {
-    let mut vec1 = vec![0; length + 1];
-    for a in t.iter() {
-        a.nodes.iter().for_each(|x| vec1[*x as usize] = 1);
-    }
-    vec1
-}
+/// Measures: Pan, Core and each similarity level (Level:k), each in nodes and sequence [bp]
+/// Edges: number of edges only (no sequence)
+/// Output: [number of genomes, measure, unit, (number of runs, mean, median, std, 2.5th percentile, 97.5th percentile)]
+pub fn bootstrap_summary(
+    data: &[(usize, usize, (Vec<usize>, Vec<usize>))],
+    edges: bool,
+) -> Vec<(usize, String, String, (usize, f64, f64, f64, f64, f64))> {
+    let units: &[&str] = if edges {
+        &["#Edge"]
+    } else {
+        &["#Node", "[bp]"]
+    };
+    let mut sizes: Vec<usize> = data.iter().map(|x| x.0).collect();
+    sizes.sort();
+    sizes.dedup();
+    let mut result = Vec::new();
+    for size in sizes.iter() {
+        let runs: Vec<&(Vec<usize>, Vec<usize>)> =
+            data.iter().filter(|x| x.0 == *size).map(|x| &x.2).collect();
 
-/// Remove one vector from the other
-/// Req:
-///     - Vectors must be of same size
-///     - Inplace operation
-pub fn remove_from_vec(origin: &mut Vec<u32>, sub: &Vec<u32>) {
-    if origin.len() != sub.len() {
-        panic!("Vectors must be of same size")
-    }
-    origin
-        .iter_mut()
-        .zip(sub.iter())
-        .for_each(|(o, s)| *o -= *s);
+        // All measures for one run: pan, core, level 1..size
+        let mut measures: Vec<String> = vec!["Pan".to_string(), "Core".to_string()];
+        measures.extend((1..=*size).map(|x| format!("Level:{}", x)));
+        for (unit_index, unit) in units.iter().enumerate() {
+            let values: Vec<Vec<f64>> = runs
+                .iter()
+                .map(|run| {
+                    let v = if unit_index == 0 { &run.0 } else { &run.1 };
+                    let mut m = vec![
+                        v.iter().sum::<usize>() as f64,
+                        *v.get(size - 1).unwrap_or(&0) as f64,
+                    ];
+                    m.extend((0..*size).map(|x| *v.get(x).unwrap_or(&0) as f64));
+                    m
+                })
+                .collect();
+            for (i, measure) in measures.iter().enumerate() {
+                let mut column: Vec<f64> = values.iter().map(|x| x[i]).collect();
+                column.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let average = mean(&column);
+                result.push((
+                    *size,
+                    measure.clone(),
+                    unit.to_string(),
+                    (
+                        column.len(),
+                        average,
+                        median(&column),
+                        standard_deviation(&column, average),
+                        percentile(&column, 2.5),
+                        percentile(&column, 97.5),
+                    ),
+                ));
+            }
+        }
+    }
+    result
 }
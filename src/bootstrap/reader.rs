@@ -13,6 +13,10 @@ pub fn read_meta(filename: &str) -> Vec<(usize, usize, HashSet<usize>)> {
 
     for line in reader.lines() {
         let lu = line.unwrap();
+        // Header (version, seed)
+        if lu.starts_with('#') {
+            continue;
+        }
         let ls: Vec<&str> = lu.split('\t').collect();
         let f: HashSet<usize> = ls[2]
             .split(',')
@@ -26,3 +30,28 @@ pub fn read_meta(filename: &str) -> Vec<(usize, usize, HashSet<usize>)> {
     }
     data
 }
+
+/// Read the sample order
+///
+/// One sample per line, optional second column (tab) is the group of the sample
+/// Output is a vector of [sample, group], group is the sample itself if not provided
+pub fn read_order(filename: &str) -> Vec<(String, String)> {
+    let file = File::open(filename).expect("Error: Unable to open order file");
+    let reader = BufReader::new(file);
+
+    let mut data = Vec::new();
+    for line in reader.lines() {
+        let lu = line.unwrap();
+        if lu.trim().is_empty() || lu.starts_with('#') {
+            continue;
+        }
+        let ls: Vec<&str> = lu.split('\t').collect();
+        let sample = ls[0].trim().to_string();
+        let group = match ls.get(1) {
+            Some(g) => g.trim().to_string(),
+            None => sample.clone(),
+        };
+        data.push((sample, group));
+    }
+    data
+}
@@ -3,20 +3,31 @@ use std::fs::File;
 use std::io::{BufWriter, Write};
 
 /// Write the meta file
-pub fn write_meta(data: Vec<(usize, usize, HashSet<usize>)>, filename: &str) {
+///
+/// Header (comment) contains gretl version and the random seed (NA if combinations are read from a meta file)
+pub fn write_meta(data: Vec<(usize, usize, HashSet<usize>)>, filename: &str, seed: Option<u64>) {
     eprintln!("Writing meta");
 
     let f = File::create(filename).expect("Unable to create file");
     let mut f = BufWriter::new(f);
+    writeln!(
+        f,
+        "# gretl {}\tseed: {}",
+        env!("CARGO_PKG_VERSION"),
+        seed.map_or("NA".to_string(), |x| x.to_string())
+    )
+    .expect("Not able to write");
 
     for x in data.iter() {
+        // Sorted, so the same seed results in the same file
+        let mut combination: Vec<&usize> = x.2.iter().collect();
+        combination.sort();
         writeln!(
             f,
             "{}\t{}\t{}",
             x.0,
             x.1,
-            x.2.iter()
-                .collect::<Vec<&usize>>()
+            combination
                 .iter()
                 .map(|n| n.to_string())
                 .collect::<Vec<String>>()
@@ -27,14 +38,20 @@ pub fn write_meta(data: Vec<(usize, usize, HashSet<usize>)>, filename: &str) {
 }
 
 /// Write output file
-pub fn write_output(data: Vec<(usize, usize, (Vec<usize>, Vec<usize>))>, filename: &str) {
+///
+/// Edges: number of edges only (no sequence columns)
+pub fn write_output(
+    data: Vec<(usize, usize, (Vec<usize>, Vec<usize>))>,
+    filename: &str,
+    edges: bool,
+) {
     eprintln!("Writing meta");
     //println!("Writing meta {:?}", data);
     let max_len = data.iter().map(|n| n.2 .1.len()).max().unwrap();
 
     let f = File::create(filename).expect("Unable to create file");
     let mut f = BufWriter::new(f);
-    let header = make_header(max_len);
+    let header = make_header(max_len, edges);
     writeln!(f, "{}", header).expect("Not able to write");
     for (size, run, data) in data.iter() {
         let mut y = data
@@ -51,21 +68,32 @@ pub fn write_output(data: Vec<(usize, usize, (Vec<usize>, Vec<usize>))>, filenam
             .iter()
             .map(|n| n.to_string())
             .collect::<Vec<String>>();
-        writeln!(
-            f,
-            "{}\t{}\t{}\t{}",
-            size,
-            run,
-            fillerback(&mut y, max_len),
-            fillerback(&mut y2, max_len)
-        )
-        .expect("Not able to write");
+        if edges {
+            writeln!(f, "{}\t{}\t{}", size, run, fillerback(&mut y, max_len))
+                .expect("Not able to write");
+        } else {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}",
+                size,
+                run,
+                fillerback(&mut y, max_len),
+                fillerback(&mut y2, max_len)
+            )
+            .expect("Not able to write");
+        }
     }
 }
 
 /// Create header for output
-pub fn make_header(max_len: usize) -> String {
+pub fn make_header(max_len: usize, edges: bool) -> String {
     let mut a = String::from("Size\tRun\t");
+    if edges {
+        for x in 0..max_len {
+            a = a + &format!("Edge:{}\t", x + 1);
+        }
+        return a;
+    }
     for x in 0..max_len {
         a = a + &format!("Node:{}\t", x + 1);
     }
@@ -85,3 +113,143 @@ pub fn fillerback(stri: &mut Vec<String>, maxlen: usize) -> String {
     }
     a
 }
+
+/// Write summary statistics for each number of genomes
+///
+/// Long format: one measure (Pan, Core, Level:k) and unit (#Node, [bp]) per line
+pub fn write_summary(
+    data: &Vec<(usize, String, String, (usize, f64, f64, f64, f64, f64))>,
+    filename: &str,
+) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    writeln!(
+        f,
+        "Size\tMeasure\tUnit\tRuns\tMean\tMedian\tStd\tPercentile 2.5\tPercentile 97.5"
+    )
+    .expect("Not able to write");
+    for (size, measure, unit, (runs, average, med, std, low, high)) in data.iter() {
+        writeln!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            size, measure, unit, runs, average, med, std, low, high
+        )
+        .expect("Not able to write");
+    }
+}
+
+/// Write the number of combinations for each number of genomes
+///
+/// Possible: all combinations (binomial coefficient), Used: distinct combinations in the bootstrap
+pub fn write_combinations(data: &[(usize, u128, usize)], filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    writeln!(f, "Size\tPossible\tUsed\tExhaustive").expect("Not able to write");
+    for (size, possible, used) in data.iter() {
+        writeln!(
+            f,
+            "{}\t{}\t{}\t{}",
+            size,
+            possible,
+            used,
+            if *used as u128 == *possible {
+                "yes"
+            } else {
+                "no"
+            }
+        )
+        .expect("Not able to write");
+    }
+}
+
+/// Write the cumulative growth (ordered samples)
+///
+/// New: nodes (sequence) added to the pangenome in this step
+/// Edges: number of edges only (no sequence columns)
+pub fn write_growth(
+    data: &[(
+        String,
+        usize,
+        (usize, usize),
+        (usize, usize),
+        (usize, usize),
+    )],
+    filename: &str,
+    edges: bool,
+) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    if edges {
+        writeln!(f, "Step\tGroup\tGenomes\tPan #Edge\tCore #Edge\tNew #Edge")
+            .expect("Not able to write");
+    } else {
+        writeln!(
+            f,
+            "Step\tGroup\tGenomes\tPan #Node\tPan [bp]\tCore #Node\tCore [bp]\tNew #Node\tNew [bp]"
+        )
+        .expect("Not able to write");
+    }
+    for (i, (group, genomes, pan, core, new)) in data.iter().enumerate() {
+        if edges {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                i + 1,
+                group,
+                genomes,
+                pan.0,
+                core.0,
+                new.0
+            )
+            .expect("Not able to write");
+            continue;
+        }
+        writeln!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            i + 1,
+            group,
+            genomes,
+            pan.0,
+            pan.1,
+            core.0,
+            core.1,
+            new.0,
+            new.1
+        )
+        .expect("Not able to write");
+    }
+}
+
+/// Write the mean growth curves of each group and the stratified total
+///
+/// Edges: number of edges only (no sequence columns)
+pub fn write_group_curves(
+    data: &[(String, Vec<(usize, f64, f64, f64, f64)>)],
+    filename: &str,
+    edges: bool,
+) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    if edges {
+        writeln!(f, "Group\tSize\tPan #Edge\tCore #Edge").expect("Not able to write");
+    } else {
+        writeln!(f, "Group\tSize\tPan #Node\tPan [bp]\tCore #Node\tCore [bp]")
+            .expect("Not able to write");
+    }
+    for (group, curves) in data.iter() {
+        for (size, pan_nodes, pan_bp, core_nodes, core_bp) in curves.iter() {
+            if edges {
+                writeln!(f, "{}\t{}\t{}\t{}", group, size, pan_nodes, core_nodes)
+                    .expect("Not able to write");
+                continue;
+            }
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                group, size, pan_nodes, pan_bp, core_nodes, core_bp
+            )
+            .expect("Not able to write");
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::copy_number::writer::write_copy_number;
+use crate::helpers::helper::calc_copy_number;
+use clap::ArgMatches;
+use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
+use log::info;
+
+/// Main function for copy number
+///
+/// Node x sample matrix: how often each sample traverses each node
+pub fn copy_number_main(matches: &ArgMatches) {
+    info!("Running 'gretl copy-number'");
+    let mut sep = " ";
+    if matches.is_present("Pan-SN") {
+        sep = matches.value_of("Pan-SN").unwrap();
+    }
+
+    if check_numeric_gfafile(matches.value_of("gfa").unwrap()) {
+        let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
+        graph.walk_to_path(sep);
+        if graph.paths.is_empty() {
+            panic!("Error: No path found in graph file")
+        }
+        let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
+        let output = matches.value_of("output").unwrap();
+
+        info!("Graph file: {}", matches.value_of("gfa").unwrap());
+        info!("Output file: {}", output);
+
+        let paths = wrapper.get_path_genome();
+        let data: Vec<(String, Vec<u32>)> = paths
+            .iter()
+            .map(|(name, p)| (name.clone(), calc_copy_number(p, &graph)))
+            .collect();
+
+        info!("Writing to file");
+        write_copy_number(&data, &graph, output);
+    } else {
+        panic!("Error: GFA file is not numeric");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod copy_number_main;
+mod writer;
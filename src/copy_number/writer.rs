@@ -0,0 +1,23 @@
+use gfa_reader::Gfa;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Write the copy number matrix
+///
+/// Table:
+/// - Each row is a node
+/// - Each column is a sample
+pub fn write_copy_number(data: &Vec<(String, Vec<u32>)>, graph: &Gfa<u32, (), ()>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    let names: Vec<&str> = data.iter().map(|x| x.0.as_str()).collect();
+    writeln!(f, "Node\t{}", names.join("\t")).expect("Not able to write");
+
+    for node in graph.segments.iter() {
+        let copies: Vec<String> = data
+            .iter()
+            .map(|x| x.1[node.id as usize].to_string())
+            .collect();
+        writeln!(f, "{}\t{}", node.id, copies.join("\t")).expect("Not able to write");
+    }
+}
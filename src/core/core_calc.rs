@@ -1,4 +1,7 @@
+use crate::helpers::classes::classify;
+use crate::helpers::helper::path_edges;
 use gfa_reader::{Gfa, Pansn};
+use std::collections::HashMap;
 
 ///
 pub fn pan_genome(
@@ -46,3 +49,70 @@ pub fn pan_genome(
     }
     (similarity_level, private_only)
 }
+
+/// Edge version of pan_genome
+///
+/// Classify each edge by the number of samples which traverse it (see calc_edge_similarity).
+/// Private edges are edges (unique) which are only traversed by this sample.
+pub fn pan_genome_edges(
+    gwrapper: &Pansn<u32, (), ()>,
+    stats: &HashMap<u64, u32>,
+) -> (Vec<usize>, Vec<(String, usize)>) {
+    eprintln!("Running core analysis (edges)");
+
+    let paths = gwrapper.get_path_genome();
+
+    // Private edges for each sample
+    let mut private_only: Vec<(String, usize)> = Vec::new();
+    for path in paths.iter() {
+        let mut edges: Vec<u64> = path.1.iter().flat_map(|x| path_edges(x)).collect();
+        edges.sort();
+        edges.dedup();
+        let private = edges.iter().filter(|x| stats[*x] == 1).count();
+        private_only.push((path.0.clone(), private));
+    }
+
+    // Number of edges for each level
+    let max_value = stats.values().max().unwrap_or(&0);
+    let mut similarity_level: Vec<usize> = vec![0; *max_value as usize + 1];
+    for x in stats.values() {
+        similarity_level[*x as usize] += 1;
+    }
+    (similarity_level, private_only)
+}
+
+/// Number of nodes and sequence for each class (see helpers::classes)
+///
+/// Output: [nodes, sequence] for each class (whole graph) and for each sample (distinct nodes of the sample)
+pub fn pan_genome_classes(
+    gwrapper: &Pansn<u32, (), ()>,
+    graph: &Gfa<u32, (), ()>,
+    similarity: &[u32],
+    classes: &[(String, u32)],
+) -> (Vec<(usize, usize)>, Vec<(String, Vec<(usize, usize)>)>) {
+    eprintln!("Running core analysis (classes)");
+
+    let mut total = vec![(0, 0); classes.len()];
+    for node in graph.segments.iter() {
+        if let Some(class) = classify(similarity[node.id as usize], classes) {
+            total[class].0 += 1;
+            total[class].1 += node.sequence.get_len();
+        }
+    }
+
+    let mut samples = Vec::new();
+    for (name, paths) in gwrapper.get_path_genome().iter() {
+        let mut nodes: Vec<u32> = paths.iter().flat_map(|x| x.nodes.iter()).cloned().collect();
+        nodes.sort();
+        nodes.dedup();
+        let mut sample = vec![(0, 0); classes.len()];
+        for node in nodes.iter() {
+            if let Some(class) = classify(similarity[*node as usize], classes) {
+                sample[class].0 += 1;
+                sample[class].1 += graph.get_node_by_id(node).sequence.get_len();
+            }
+        }
+        samples.push((name.clone(), sample));
+    }
+    (total, samples)
+}
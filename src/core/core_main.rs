@@ -1,15 +1,17 @@
-use crate::core::core_calc::pan_genome;
-use crate::core::writer::writer_core;
+use crate::core::core_calc::{pan_genome, pan_genome_classes, pan_genome_edges};
+use crate::core::writer::{writer_core, writer_core_classes, writer_core_edges};
+use crate::helpers::classes::{parse_classes, DEFAULT_CLASSES};
 use crate::helpers::graphs::get_stats;
+use crate::helpers::helper::{calc_edge_similarity, calc_similarity};
+use crate::helpers::region::restrict_graph;
 use clap::ArgMatches;
 use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
 
-
 /// Core main function
 ///
 /// Calculate amount of nodes and sequence for each level.
 /// Everything is written in one file.
-pub fn core_main(matches: &ArgMatches) {
+pub fn core_main(matches: &ArgMatches, regions: &[(String, u64, u64)], output: &str) {
     // Reading the graph and converting it to a graph wrapper
     eprintln!("Running 'gretl core' analysis");
     // Is the graph file numeric?
@@ -24,16 +26,34 @@ pub fn core_main(matches: &ArgMatches) {
         let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
         println!("Walking to path {}", graph.paths.len());
         graph.walk_to_path(sep);
+        if !regions.is_empty() {
+            restrict_graph(&mut graph, regions);
+        }
 
         // Check if paths are found
         if !graph.paths.is_empty() {
             let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
 
-            // Get output file name
-            let output = matches.value_of("output").unwrap();
+            // Core, shell, cloud, ... (based on similarity)
+            if matches.is_present("classify") {
+                let paths = wrapper.get_path_genome();
+                let classes = parse_classes(
+                    matches.value_of("classes").unwrap_or(DEFAULT_CLASSES),
+                    paths.len(),
+                );
+                let similarity = calc_similarity(&paths, &graph);
+                let (total, samples) = pan_genome_classes(&wrapper, &graph, &similarity, &classes);
+                writer_core_classes(total, samples, &classes, output);
+            }
 
             // Which kind of stats
             let kind = matches.value_of("statistics").unwrap();
+            if kind == "edge" {
+                let stats = calc_edge_similarity(&wrapper.get_path_genome());
+                let (similarity_level, private_only) = pan_genome_edges(&wrapper, &stats);
+                writer_core_edges(similarity_level, private_only, output);
+                return;
+            }
             let stats = get_stats(&wrapper, &graph, kind);
 
             // Get the data
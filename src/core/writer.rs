@@ -25,3 +25,56 @@ pub fn writer_core(
         writeln!(f, "{}\t{}\t{}", name, seq, nodes).expect("Not able to write");
     }
 }
+
+/// Write table for edges (similarity level and private edges)
+pub fn writer_core_edges(
+    data_total: Vec<usize>,
+    data_private: Vec<(String, usize)>,
+    filename: &str,
+) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    // Header
+    writeln!(f, "Similarity\t#Edge").expect("Not able to write");
+    for (i, x) in data_total.iter().enumerate() {
+        writeln!(f, "{}\t{}", i, x).expect("Not able to write");
+    }
+
+    let f = File::create(filename.to_string() + ".private.txt").expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+
+    writeln!(f, "Path\t#Edge").expect("Not able to write");
+    for (name, edges) in data_private.iter() {
+        writeln!(f, "{}\t{}", name, edges).expect("Not able to write");
+    }
+}
+
+/// Write the classes (whole graph and each sample)
+///
+/// Min: minimum number of samples of the class
+pub fn writer_core_classes(
+    data_total: Vec<(usize, usize)>,
+    data_samples: Vec<(String, Vec<(usize, usize)>)>,
+    classes: &[(String, u32)],
+    filename: &str,
+) {
+    let f = File::create(filename.to_string() + ".classes.txt").expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    writeln!(f, "Class\tMin\tSequence[bp]\t#Node").expect("Not able to write");
+    for ((name, min), (nodes, seq)) in classes.iter().zip(data_total.iter()) {
+        writeln!(f, "{}\t{}\t{}\t{}", name, min, seq, nodes).expect("Not able to write");
+    }
+
+    let f =
+        File::create(filename.to_string() + ".classes.samples.txt").expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    let seq: Vec<String> = classes.iter().map(|x| format!("{}[bp]", x.0)).collect();
+    let nodes: Vec<String> = classes.iter().map(|x| format!("{}#Node", x.0)).collect();
+    writeln!(f, "Path\t{}\t{}", seq.join("\t"), nodes.join("\t")).expect("Not able to write");
+    for (name, data) in data_samples.iter() {
+        let seq: Vec<String> = data.iter().map(|x| x.1.to_string()).collect();
+        let nodes: Vec<String> = data.iter().map(|x| x.0.to_string()).collect();
+        writeln!(f, "{}\t{}\t{}", name, seq.join("\t"), nodes.join("\t"))
+            .expect("Not able to write");
+    }
+}
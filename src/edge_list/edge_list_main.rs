@@ -0,0 +1,64 @@
+use crate::edge_list::wrapper::{missing_links, wrapper_edge};
+use crate::edge_list::writer::{write_edges, write_missing};
+use clap::ArgMatches;
+use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
+use log::info;
+
+/// Main function for edge list
+pub fn edgelist_main(matches: &ArgMatches) {
+    info!("Running 'gretl edge-list'");
+    let mut sep = " ";
+    if matches.is_present("Pan-SN") {
+        sep = matches.value_of("Pan-SN").unwrap();
+    }
+
+    if check_numeric_gfafile(matches.value_of("gfa").unwrap()) {
+        // Parse GFA file + Wrapper
+        let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
+        graph.walk_to_path(sep);
+        if graph.paths.is_empty() {
+            panic!("Error: No path found in graph file")
+        }
+
+        let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
+
+        // Other inputs
+        let output = matches.value_of("output").unwrap();
+        let splits = vec!["Depth", "Similarity"];
+        let mut split_vec = Vec::new();
+        if matches.is_present("Features") {
+            split_vec = matches.value_of("Features").unwrap().split(',').collect();
+        }
+        let mut final_features = Vec::new();
+        for x in split_vec.iter() {
+            if splits.contains(x) {
+                final_features.push(*x);
+            }
+        }
+
+        if final_features.is_empty() {
+            final_features = splits.clone();
+        }
+        info!("Graph file: {}", matches.value_of("gfa").unwrap());
+        info!("Output file: {}", output);
+        info!("Features: {:?}", final_features);
+
+        info!("Calculating edge statistics");
+        let data = wrapper_edge(&graph, &wrapper);
+        let unused = data.iter().filter(|x| x.1 && x.2 == 0).count();
+        info!("Links not used by any path: {}", unused);
+
+        let missing = missing_links(&graph);
+        info!(
+            "Path steps using links absent from the graph: {}",
+            missing.len()
+        );
+
+        info!("Writing to file");
+        write_edges(&data, &final_features, output);
+        write_missing(&missing, &(output.to_string() + ".missing.txt"));
+        info!("Finished writing to file");
+    } else {
+        panic!("Error: GFA file is not numeric");
+    }
+}
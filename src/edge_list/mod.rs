@@ -0,0 +1,3 @@
+pub mod edge_list_main;
+mod wrapper;
+mod writer;
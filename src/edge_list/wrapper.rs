@@ -0,0 +1,49 @@
+use crate::helpers::helper::{calc_edge_depth, calc_edge_similarity, graph_edges, path_edges};
+use gfa_reader::{Gfa, Pansn};
+
+/// Wrapper function for edge list analysis
+///
+/// Covers all links of the graph and all edges traversed by a path.
+/// Output is a vector of [edge, is a link (L line), depth, similarity]
+pub fn wrapper_edge(
+    graph: &Gfa<u32, (), ()>,
+    wrapper: &Pansn<u32, (), ()>,
+) -> Vec<(u64, bool, u32, u32)> {
+    let paths = wrapper.get_path_genome();
+
+    let links = graph_edges(graph);
+    let depth = calc_edge_depth(&paths);
+    let similarity = calc_edge_similarity(&paths);
+
+    let mut edges: Vec<u64> = links.iter().chain(depth.keys()).cloned().collect();
+    edges.sort();
+    edges.dedup();
+
+    edges
+        .iter()
+        .map(|edge| {
+            (
+                *edge,
+                links.contains(edge),
+                *depth.get(edge).unwrap_or(&0),
+                *similarity.get(edge).unwrap_or(&0),
+            )
+        })
+        .collect()
+}
+
+/// Path steps which use an edge that is not in the graph (no L line)
+///
+/// Output is a vector of [path name, step index, edge]
+pub fn missing_links(graph: &Gfa<u32, (), ()>) -> Vec<(String, usize, u64)> {
+    let links = graph_edges(graph);
+    let mut result = Vec::new();
+    for path in graph.paths.iter() {
+        for (i, edge) in path_edges(path).iter().enumerate() {
+            if !links.contains(edge) {
+                result.push((path.name.clone(), i + 1, *edge));
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,46 @@
+use crate::find::find_main::{to_string1, Feature};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Write the edge list
+///
+/// One edge per line, edges are written as in 'gretl find' (e.g. 1+2+)
+pub fn write_edges(data: &Vec<(u64, bool, u32, u32)>, what: &Vec<&str>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    write!(f, "Edge\tLink").expect("Not able to write");
+    for x in what.iter() {
+        write!(f, "\t{}", x).expect("Not able to write");
+    }
+    writeln!(f).expect("Not able to write");
+
+    for (edge, link, depth, similarity) in data.iter() {
+        write!(f, "{}\t{}", to_string1(*edge, &Feature::Edge), *link as u8)
+            .expect("Not able to write");
+        for x in what.iter() {
+            if *x == "Depth" {
+                write!(f, "\t{}", depth).expect("Not able to write");
+            } else {
+                write!(f, "\t{}", similarity).expect("Not able to write");
+            }
+        }
+        writeln!(f).expect("Not able to write");
+    }
+}
+
+/// Write all path steps which use a link absent from the graph
+pub fn write_missing(data: &Vec<(String, usize, u64)>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    writeln!(f, "Path\tStep\tEdge").expect("Not able to write");
+    for (name, step, edge) in data.iter() {
+        writeln!(
+            f,
+            "{}\t{}\t{}",
+            name,
+            step,
+            to_string1(*edge, &Feature::Edge)
+        )
+        .expect("Not able to write");
+    }
+}
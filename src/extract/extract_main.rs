@@ -0,0 +1,171 @@
+use crate::bootstrap::helper::read_positive_integers_from_file;
+use crate::extract::writer::write_subgraph;
+use crate::feature::feature_main::{feature_filter, feature_filter_set, feature_thresholds};
+use crate::helpers::helper::calc_node_len;
+use crate::helpers::region::{read_regions, region_nodes};
+use clap::ArgMatches;
+use gfa_reader::{check_numeric_gfafile, Gfa, Pansn, Path};
+use log::info;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+/// Main function for extract subcommand
+///
+/// Write a subgraph (GFA) of selected nodes, the links between them and the path fragments
+pub fn extract_main(matches: &ArgMatches) {
+    info!("Running 'gretl extract'");
+    let mut sep = "#";
+    if matches.is_present("Pan-SN") {
+        sep = matches.value_of("Pan-SN").unwrap();
+    }
+
+    let graph_file = matches.value_of("gfa").unwrap();
+    if check_numeric_gfafile(graph_file) {
+        let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(graph_file);
+        let p_paths = graph.paths.len();
+        graph.walk_to_path(sep);
+        if graph.paths.is_empty() {
+            panic!("Error: No path found in graph file")
+        }
+        let output = matches.value_of("output").unwrap();
+        info!("Gfa file: {}", graph_file);
+        info!("Output file: {}", output);
+
+        // All selections are intersected
+        let mut selections: Vec<HashSet<u32>> = Vec::new();
+        if matches.is_present("nodes") {
+            let nodes = read_positive_integers_from_file(matches.value_of("nodes").unwrap());
+            selections.push(nodes.into_iter().collect());
+        }
+        if matches.is_present("paths") {
+            let names = read_path_names(matches.value_of("paths").unwrap());
+            selections.push(path_nodes(&graph, &names));
+        }
+        let regions = read_regions(matches);
+        if !regions.is_empty() {
+            selections.push(region_nodes(&graph, &regions));
+        }
+        if feature_filter_set(matches) {
+            let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
+            let (mindepth, mindegree, minlen, maxlen, maxdegree, maxdepth) =
+                feature_thresholds(matches);
+            let nodes = feature_filter(
+                &graph,
+                &wrapper,
+                mindepth,
+                mindegree,
+                minlen,
+                maxlen,
+                maxdegree,
+                maxdepth,
+                matches.is_present("bi-oriented"),
+            );
+            selections.push(nodes.iter().map(|x| *x as u32).collect());
+        }
+        if selections.is_empty() {
+            panic!("Error: No selection (nodes, paths, region or feature filter) provided")
+        }
+        let mut nodes = selections[0].clone();
+        for selection in selections.iter().skip(1) {
+            nodes.retain(|n| selection.contains(n));
+        }
+        info!("Selected nodes: {}", nodes.len());
+
+        let node_len = calc_node_len(&graph);
+        // Walks are added after the paths (in file order), paths start at 0
+        let mut offsets = vec![0; p_paths];
+        offsets.extend(walk_starts(graph_file));
+        if offsets.len() != graph.paths.len() {
+            panic!("Error: Number of walks does not match the W lines in the graph file")
+        }
+        let mut fragments = Vec::new();
+        for (path, offset) in graph.paths.iter().zip(offsets.iter()) {
+            // Fragments are named by their subrange (name:start-end), which keeps the PanSN name intact
+            for ((start, end), n, d) in path_fragments(path, &nodes, &node_len, *offset) {
+                fragments.push((format!("{}:{}-{}", path.name, start, end), n, d));
+            }
+        }
+        info!("Path fragments: {}", fragments.len());
+
+        info!("Writing to file");
+        write_subgraph(graph_file, &nodes, &fragments, output);
+    } else {
+        panic!("Error: GFA file is not numeric");
+    }
+}
+
+/// Read path names (one per line, e.g. output of 'gretl path')
+pub fn read_path_names(filename: &str) -> HashSet<String> {
+    let file = File::open(filename).expect("Failed to open file");
+    let reader = BufReader::new(file);
+    reader
+        .lines()
+        .map(|x| x.unwrap().trim().to_string())
+        .filter(|x| !x.is_empty())
+        .collect()
+}
+
+/// All nodes traversed by the paths
+pub fn path_nodes(graph: &Gfa<u32, (), ()>, names: &HashSet<String>) -> HashSet<u32> {
+    let mut nodes = HashSet::new();
+    for path in graph.paths.iter().filter(|p| names.contains(&p.name)) {
+        nodes.extend(path.nodes.iter().cloned());
+    }
+    nodes
+}
+
+/// Start positions of the walks (W lines), in file order
+pub fn walk_starts(graph_file: &str) -> Vec<u64> {
+    let reader = BufReader::new(File::open(graph_file).expect("Unable to open file"));
+    let mut starts = Vec::new();
+    for line in reader.lines() {
+        let line = line.unwrap();
+        if line.starts_with("W\t") {
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < 7 {
+                panic!("Error: W line has less than 7 columns: {}", line);
+            }
+            starts.push(
+                fields[4]
+                    .parse()
+                    .unwrap_or_else(|_| panic!("Error: Walk start is not numeric: {}", fields[4])),
+            );
+        }
+    }
+    starts
+}
+
+/// Split a path at excluded nodes
+///
+/// Each fragment covers a subrange of the original path (start, end exclusive)
+/// Offset: start of the path on its sequence (walks), 0 otherwise
+/// Output is a vector of [(start, end), nodes, directions]
+pub fn path_fragments(
+    path: &Path<u32, (), ()>,
+    nodes: &HashSet<u32>,
+    node_len: &Vec<u32>,
+    offset: u64,
+) -> Vec<((u64, u64), Vec<u32>, Vec<bool>)> {
+    let mut result = Vec::new();
+    let mut pos: u64 = offset;
+    let mut start: u64 = 0;
+    let mut fragment: (Vec<u32>, Vec<bool>) = (Vec::new(), Vec::new());
+    for (n, d) in path.nodes.iter().zip(path.dir.iter()) {
+        if nodes.contains(n) {
+            if fragment.0.is_empty() {
+                start = pos;
+            }
+            fragment.0.push(*n);
+            fragment.1.push(*d);
+        } else if !fragment.0.is_empty() {
+            let (f_nodes, f_dir) = std::mem::take(&mut fragment);
+            result.push(((start, pos), f_nodes, f_dir));
+        }
+        pos += node_len[*n as usize] as u64;
+    }
+    if !fragment.0.is_empty() {
+        result.push(((start, pos), fragment.0, fragment.1));
+    }
+    result
+}
@@ -0,0 +1,2 @@
+pub mod extract_main;
+mod writer;
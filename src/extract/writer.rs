@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+/// Write the subgraph in GFA format
+///
+/// Header, segments (incl. sequence and tags) and links are copied from the input file, paths are replaced by the path fragments
+pub fn write_subgraph(
+    graph_file: &str,
+    nodes: &HashSet<u32>,
+    fragments: &Vec<(String, Vec<u32>, Vec<bool>)>,
+    filename: &str,
+) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    let reader = BufReader::new(File::open(graph_file).expect("Unable to open file"));
+    for line in reader.lines() {
+        let line = line.unwrap();
+        let fields: Vec<&str> = line.split('\t').collect();
+        let keep = match fields[0] {
+            _ if line.starts_with('H') => true,
+            "S" => nodes.contains(&fields[1].parse().unwrap()),
+            "L" => {
+                nodes.contains(&fields[1].parse().unwrap())
+                    && nodes.contains(&fields[3].parse().unwrap())
+            }
+            _ => false,
+        };
+        if keep {
+            writeln!(f, "{}", line).expect("Not able to write");
+        }
+    }
+    for (name, path_nodes, dirs) in fragments.iter() {
+        let steps: Vec<String> = path_nodes
+            .iter()
+            .zip(dirs.iter())
+            .map(|(n, d)| format!("{}{}", n, if *d { "+" } else { "-" }))
+            .collect();
+        writeln!(f, "P\t{}\t{}\t*", name, steps.join(",")).expect("Not able to write");
+    }
+}
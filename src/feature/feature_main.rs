@@ -1,4 +1,4 @@
-use crate::helpers::helper::{calc_depth, calc_node_degree, calc_node_len};
+use crate::helpers::helper::{calc_depth, calc_node_degree, calc_node_len, calc_orientation};
 use clap::ArgMatches;
 use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
 use log::info;
@@ -12,29 +12,10 @@ pub fn feature_main(matches: &ArgMatches) {
         let graph_file = matches.value_of("gfa").unwrap();
         let file_output = matches.value_of("output").unwrap();
 
-        let mut maxlen: i128 = matches.value_of("max-len").unwrap_or("-9").parse().unwrap();
-        let minlen: i128 = matches.value_of("min-len").unwrap_or("-9").parse().unwrap();
-        let mut maxdegree: i128 = matches
-            .value_of("max-depth")
-            .unwrap_or("-9")
-            .parse()
-            .unwrap();
-        let mindegree: i128 = matches
-            .value_of("min-depth")
-            .unwrap_or("-9")
-            .parse()
-            .unwrap();
-        let mut maxdepth: i128 = matches
-            .value_of("max-depth")
-            .unwrap_or("-9")
-            .parse()
-            .unwrap();
-        let mindepth: i128 = matches
-            .value_of("min-depth")
-            .unwrap_or("-9")
-            .parse()
-            .unwrap();
+        let (mindepth, mindegree, minlen, maxlen, maxdegree, maxdepth) =
+            feature_thresholds(matches);
         let pansn_sep = matches.value_of("PanSN").unwrap_or(" ");
+        let bi_oriented = matches.is_present("bi-oriented");
 
         info!("Running feature filter");
 
@@ -46,24 +27,8 @@ pub fn feature_main(matches: &ArgMatches) {
         }
         let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, pansn_sep);
 
-        if maxdegree == maxdepth
-            && maxdegree == maxlen
-            && maxdegree == -9
-            && mindepth == maxdegree
-            && mindegree == maxdegree
-            && minlen == maxdegree
-        {
+        if !feature_filter_set(matches) {
             return;
-        } else {
-            if maxlen == -9 {
-                maxlen = i128::MAX;
-            }
-            if maxdepth == -9 {
-                maxdepth = i128::MAX;
-            }
-            if maxdegree == -9 {
-                maxdegree = i128::MAX;
-            }
         }
 
         info!("Graph file: {}", graph_file);
@@ -74,11 +39,20 @@ pub fn feature_main(matches: &ArgMatches) {
         info!("Min degree: {}", mindegree);
         info!("Max depth: {}", maxdepth);
         info!("Min depth: {}", mindepth);
+        info!("Bi-oriented: {}", bi_oriented);
         info!("PanSN separator: {}", pansn_sep);
 
         // Run filter
         let result = feature_filter(
-            &graph, &wrapper, mindepth, mindegree, minlen, maxlen, maxdegree, maxdepth,
+            &graph,
+            &wrapper,
+            mindepth,
+            mindegree,
+            minlen,
+            maxlen,
+            maxdegree,
+            maxdepth,
+            bi_oriented,
         );
 
         info!("Write output");
@@ -89,6 +63,51 @@ pub fn feature_main(matches: &ArgMatches) {
     }
 }
 
+/// Parse the filter thresholds (feature options)
+///
+/// Output: (min depth, min degree, min length, max length, max degree, max depth)
+/// Not set: minimum -> -9, maximum -> i128::MAX
+pub fn feature_thresholds(matches: &ArgMatches) -> (i128, i128, i128, i128, i128, i128) {
+    let mut maxlen: i128 = matches.value_of("max-len").unwrap_or("-9").parse().unwrap();
+    let minlen: i128 = matches.value_of("min-len").unwrap_or("-9").parse().unwrap();
+    let mut maxdegree: i128 = matches
+        .value_of("max-degree")
+        .unwrap_or("-9")
+        .parse()
+        .unwrap();
+    let mindegree: i128 = matches
+        .value_of("min-degree")
+        .unwrap_or("-9")
+        .parse()
+        .unwrap();
+    let mut maxdepth: i128 = matches
+        .value_of("max-depth")
+        .unwrap_or("-9")
+        .parse()
+        .unwrap();
+    let mindepth: i128 = matches
+        .value_of("min-depth")
+        .unwrap_or("-9")
+        .parse()
+        .unwrap();
+    if maxlen == -9 {
+        maxlen = i128::MAX;
+    }
+    if maxdepth == -9 {
+        maxdepth = i128::MAX;
+    }
+    if maxdegree == -9 {
+        maxdegree = i128::MAX;
+    }
+    (mindepth, mindegree, minlen, maxlen, maxdegree, maxdepth)
+}
+
+/// Is any feature filter set?
+pub fn feature_filter_set(matches: &ArgMatches) -> bool {
+    feature_thresholds(matches) != (-9, -9, -9, i128::MAX, i128::MAX, i128::MAX)
+        || matches.is_present("bi-oriented")
+}
+
 /// Filter feature by length, degree and depth
 pub fn feature_filter(
     graph: &Gfa<u32, (), ()>,
@@ -99,6 +118,7 @@ pub fn feature_filter(
     maxlen: i128,
     maxdegree: i128,
     maxdepth: i128,
+    bi_oriented: bool,
 ) -> Vec<usize> {
     let paths = wrapper.get_path_genome();
 
@@ -106,7 +126,11 @@ pub fn feature_filter(
     let size = calc_node_len(graph);
     let degree = calc_node_degree(graph).2;
     let depth = calc_depth(&paths, graph);
+    let (forward, reverse) = calc_orientation(&paths, graph);
     for (i, (s, (deg, dep))) in size.iter().zip(degree.iter().zip(depth.iter())).enumerate() {
+        if bi_oriented && (forward[i] == 0 || reverse[i] == 0) {
+            continue;
+        }
         if s != &0 && *s as i128 > minlen
                 && *deg as i128 > mindegree
                 && *dep as i128 > mindepth
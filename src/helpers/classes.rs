@@ -0,0 +1,46 @@
+/// Default classes: core (all samples), soft-core (at least 95% of the samples), shell (at least 2 samples), cloud (1 sample)
+pub const DEFAULT_CLASSES: &str = "core:100%,soft-core:95%,shell:2,cloud:1";
+
+/// Parse the class definitions (name:min, comma separated)
+///
+/// min is the minimum number of samples, either absolute (2) or relative to all samples (95%)
+/// Relative minimums below 100% are at most all samples - 1, otherwise they are the same as core on small graphs
+/// Output is a vector of [name, minimum number of samples], highest minimum first
+pub fn parse_classes(spec: &str, samples: usize) -> Vec<(String, u32)> {
+    let mut classes = Vec::new();
+    for class in spec.split(',') {
+        let (name, min) = class
+            .split_once(':')
+            .unwrap_or_else(|| panic!("Error: Class {} is not name:min", class));
+        let min = match min.strip_suffix('%') {
+            Some(percent) => {
+                let percent: f64 = percent
+                    .parse()
+                    .unwrap_or_else(|_| panic!("Error: Class {} is not numeric", class));
+                let min = (percent / 100.0 * samples as f64).ceil() as u32;
+                if percent < 100.0 && samples > 1 {
+                    min.min(samples as u32 - 1)
+                } else {
+                    min
+                }
+            }
+            None => min
+                .parse()
+                .unwrap_or_else(|_| panic!("Error: Class {} is not numeric", class)),
+        };
+        classes.push((name.to_string(), min));
+    }
+    // Stable: classes with the same minimum keep their order (first one wins)
+    classes.sort_by(|a, b| b.1.cmp(&a.1));
+    classes
+}
+
+/// Class (index) of a node with this similarity
+///
+/// The first class with a minimum <= similarity, None for nodes without any sample
+pub fn classify(similarity: u32, classes: &[(String, u32)]) -> Option<usize> {
+    if similarity == 0 {
+        return None;
+    }
+    classes.iter().position(|x| similarity >= x.1)
+}
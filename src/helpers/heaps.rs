@@ -0,0 +1,117 @@
+/// Power law fit (y = kappa * N^(-alpha)) with 95% confidence intervals
+#[derive(Debug, Clone)]
+pub struct PowerLaw {
+    pub kappa: f64,
+    pub kappa_ci: (f64, f64),
+    pub alpha: f64,
+    pub alpha_ci: (f64, f64),
+    pub r2: f64,
+    pub points: usize,
+}
+
+/// Two-sided 95% quantile of the t-distribution
+fn t_quantile(df: usize) -> f64 {
+    const T975: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+        2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+        2.052, 2.048, 2.045, 2.042,
+    ];
+    match df {
+        0 => f64::NAN,
+        1..=30 => T975[df - 1],
+        _ => 1.96,
+    }
+}
+
+/// Fit y = kappa * N^(-alpha) by linear regression in log-log space
+///
+/// Points with y <= 0 are ignored. Confidence intervals need at least 3 points.
+pub fn power_law_fit(data: &[(f64, f64)]) -> PowerLaw {
+    let points: Vec<(f64, f64)> = data
+        .iter()
+        .filter(|(x, y)| *x > 0.0 && *y > 0.0)
+        .map(|(x, y)| (x.ln(), y.ln()))
+        .collect();
+    let k = points.len();
+    if k < 2 {
+        return PowerLaw {
+            kappa: f64::NAN,
+            kappa_ci: (f64::NAN, f64::NAN),
+            alpha: f64::NAN,
+            alpha_ci: (f64::NAN, f64::NAN),
+            r2: f64::NAN,
+            points: k,
+        };
+    }
+    let x_mean = points.iter().map(|p| p.0).sum::<f64>() / k as f64;
+    let y_mean = points.iter().map(|p| p.1).sum::<f64>() / k as f64;
+    let sxx: f64 = points.iter().map(|p| (p.0 - x_mean).powi(2)).sum();
+    let sxy: f64 = points.iter().map(|p| (p.0 - x_mean) * (p.1 - y_mean)).sum();
+    let syy: f64 = points.iter().map(|p| (p.1 - y_mean).powi(2)).sum();
+    let slope = sxy / sxx;
+    let intercept = y_mean - slope * x_mean;
+    let ssr: f64 = points
+        .iter()
+        .map(|p| (p.1 - intercept - slope * p.0).powi(2))
+        .sum();
+
+    // Standard errors (residual variance with k - 2 degrees of freedom)
+    let t = if k > 2 { t_quantile(k - 2) } else { f64::NAN };
+    let s = (ssr / (k as f64 - 2.0)).sqrt();
+    let se_slope = s / sxx.sqrt();
+    let se_intercept = s * (1.0 / k as f64 + x_mean * x_mean / sxx).sqrt();
+
+    PowerLaw {
+        kappa: intercept.exp(),
+        kappa_ci: (
+            (intercept - t * se_intercept).exp(),
+            (intercept + t * se_intercept).exp(),
+        ),
+        alpha: -slope,
+        alpha_ci: (-slope - t * se_slope, -slope + t * se_slope),
+        r2: if syy > 0.0 { 1.0 - ssr / syy } else { 1.0 },
+        points: k,
+    }
+}
+
+/// Heaps' law for pan and core curves (nodes and sequence)
+///
+/// Input: [number of samples, pan nodes, pan bp, core nodes, core bp], sorted by number of samples
+/// - Pan: new nodes/sequence added by the N-th sample (pan(N) - pan(N-1)) = kappa * N^(-alpha). Pangenome is open if alpha < 1
+/// - Core: core(N) = kappa * N^(-alpha)
+///
+/// Output: [curve, unit, fit]
+pub fn heaps_law(data: &[(usize, f64, f64, f64, f64)]) -> Vec<(String, String, PowerLaw)> {
+    let mut new_nodes = Vec::new();
+    let mut new_bp = Vec::new();
+    for w in data.windows(2) {
+        if w[1].0 == w[0].0 + 1 {
+            new_nodes.push((w[1].0 as f64, w[1].1 - w[0].1));
+            new_bp.push((w[1].0 as f64, w[1].2 - w[0].2));
+        }
+    }
+    let core_nodes: Vec<(f64, f64)> = data.iter().map(|x| (x.0 as f64, x.3)).collect();
+    let core_bp: Vec<(f64, f64)> = data.iter().map(|x| (x.0 as f64, x.4)).collect();
+    vec![
+        (
+            "Pan".to_string(),
+            "#Node".to_string(),
+            power_law_fit(&new_nodes),
+        ),
+        (
+            "Pan".to_string(),
+            "[bp]".to_string(),
+            power_law_fit(&new_bp),
+        ),
+        (
+            "Core".to_string(),
+            "#Node".to_string(),
+            power_law_fit(&core_nodes),
+        ),
+        (
+            "Core".to_string(),
+            "[bp]".to_string(),
+            power_law_fit(&core_bp),
+        ),
+    ]
+}
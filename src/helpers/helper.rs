@@ -1,4 +1,7 @@
+use crate::find::find_main::merge_u32_to_u64;
 use gfa_reader::{Gfa, Path};
+use std::cmp::{max, min};
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 
 #[allow(dead_code)]
@@ -56,6 +59,113 @@ pub fn calc_similarity(
     depth
 }
 
+/// Orientation of each node
+///
+/// Return number of samples which traverse the node forward (+) and reverse (-).
+/// A sample can be counted in both vectors.
+pub fn calc_orientation(
+    wrapper: &Vec<(String, Vec<&Path<u32, (), ()>>)>,
+    graph: &Gfa<u32, (), ()>,
+) -> (Vec<u32>, Vec<u32>) {
+    let max_id = graph.segments.iter().max().unwrap().id as usize;
+    let mut forward: Vec<u32> = vec![0; max_id + 1];
+    let mut reverse: Vec<u32> = vec![0; max_id + 1];
+    for p in wrapper.iter() {
+        let mut path_nodes: Vec<(u32, bool)> =
+            p.1.iter()
+                .flat_map(|x| x.nodes.iter().cloned().zip(x.dir.iter().cloned()))
+                .collect();
+        path_nodes.sort();
+        path_nodes.dedup();
+        for (node, dir) in path_nodes.iter() {
+            if *dir {
+                forward[*node as usize] += 1;
+            } else {
+                reverse[*node as usize] += 1;
+            }
+        }
+    }
+    (forward, reverse)
+}
+
+/// Copy number of each node in a single sample
+///
+/// Number of times a single path traverses the node, maximum over all paths of the sample.
+/// Paths (e.g. haplotypes) are not summed up: a diploid sample has copy number 1 on all nodes which are not repeated within a haplotype.
+pub fn calc_copy_number(paths: &Vec<&Path<u32, (), ()>>, graph: &Gfa<u32, (), ()>) -> Vec<u32> {
+    let mut copies: Vec<u32> = vec![0; graph.segments.iter().max().unwrap().id as usize + 1];
+    let mut path_copies: Vec<u32> = vec![0; copies.len()];
+    for path in paths.iter() {
+        for x in path.nodes.iter() {
+            path_copies[*x as usize] += 1;
+        }
+        for x in path.nodes.iter() {
+            copies[*x as usize] = max(copies[*x as usize], path_copies[*x as usize]);
+        }
+        // Reset for the next path
+        for x in path.nodes.iter() {
+            path_copies[*x as usize] = 0;
+        }
+    }
+    copies
+}
+
+/// Identifier of an edge (u64)
+///
+/// Directed nodes are encoded as node * 2 + dir (same as 'gretl find'). An edge and its
+/// reverse complement (1+2+ == 2-1-) describe the same link, the smaller value is returned.
+pub fn edge_key(from: u32, from_dir: bool, to: u32, to_dir: bool) -> u64 {
+    let forward = merge_u32_to_u64(from * 2 + from_dir as u32, to * 2 + to_dir as u32);
+    let reverse = merge_u32_to_u64(to * 2 + !to_dir as u32, from * 2 + !from_dir as u32);
+    min(forward, reverse)
+}
+
+/// All edges (L lines) of the graph
+pub fn graph_edges(graph: &Gfa<u32, (), ()>) -> HashSet<u64> {
+    graph
+        .links
+        .iter()
+        .map(|l| edge_key(l.from, l.from_dir, l.to, l.to_dir))
+        .collect()
+}
+
+/// All edges traversed by a path (in order)
+pub fn path_edges(path: &Path<u32, (), ()>) -> Vec<u64> {
+    path.nodes
+        .iter()
+        .zip(path.dir.iter())
+        .zip(path.nodes.iter().zip(path.dir.iter()).skip(1))
+        .map(|((n1, d1), (n2, d2))| edge_key(*n1, *d1, *n2, *d2))
+        .collect()
+}
+
+/// Counting how often each edge is traversed (edge depth)
+pub fn calc_edge_depth(wrapper: &Vec<(String, Vec<&Path<u32, (), ()>>)>) -> HashMap<u64, u32> {
+    let mut depth: HashMap<u64, u32> = HashMap::new();
+    for paths in wrapper.iter() {
+        for path in paths.1.iter() {
+            for edge in path_edges(path) {
+                *depth.entry(edge).or_insert(0) += 1;
+            }
+        }
+    }
+    depth
+}
+
+/// Counting the number of samples which traverse each edge (edge similarity)
+pub fn calc_edge_similarity(wrapper: &Vec<(String, Vec<&Path<u32, (), ()>>)>) -> HashMap<u64, u32> {
+    let mut similarity: HashMap<u64, u32> = HashMap::new();
+    for p in wrapper.iter() {
+        let mut edges: Vec<u64> = p.1.iter().flat_map(|x| path_edges(x)).collect();
+        edges.sort();
+        edges.dedup();
+        for edge in edges.iter() {
+            *similarity.entry(*edge).or_insert(0) += 1;
+        }
+    }
+    similarity
+}
+
 /// Calculate node degree (in, out, total)
 pub fn calc_node_degree(graph: &Gfa<u32, (), ()>) -> (Vec<u32>, Vec<u32>, Vec<u32>) {
     let mut degree_in: Vec<u32> = vec![0; graph.segments.iter().max().unwrap().id as usize + 1];
@@ -120,6 +230,20 @@ where
     }
 }
 
+/// Percentile (nearest rank) of a sorted vector
+///
+/// p is between 0 and 100
+pub fn percentile<T>(sorted_data: &[T], p: f64) -> f64
+where
+    T: Into<f64> + Copy,
+{
+    if sorted_data.is_empty() {
+        return f64::NAN;
+    }
+    let rank = ((p / 100.0) * sorted_data.len() as f64).ceil() as usize;
+    sorted_data[rank.clamp(1, sorted_data.len()) - 1].into()
+}
+
 pub fn standard_deviation<T>(data: &[T], mean: f64) -> f64
 where
     T: Into<f64> + Copy,
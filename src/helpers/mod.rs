@@ -1,2 +1,5 @@
+pub mod classes;
 pub mod graphs;
+pub mod heaps;
 pub mod helper;
+pub mod region;
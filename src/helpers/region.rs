@@ -0,0 +1,213 @@
+use crate::extract::extract_main::path_fragments;
+use crate::helpers::helper::calc_node_len;
+use clap::ArgMatches;
+use gfa_reader::Gfa;
+use log::info;
+use std::collections::HashSet;
+use std::fs;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use tempfile::tempdir;
+
+/// Read all regions (--region and --region-bed)
+///
+/// Region format: path:start-end (0-based, end exclusive, like BED)
+/// Output is a vector of [path, start, end]
+pub fn read_regions(matches: &ArgMatches) -> Vec<(String, u64, u64)> {
+    let mut regions = Vec::new();
+    if let Some(region) = matches.value_of("region") {
+        for r in region.split(',') {
+            regions.push(parse_region(r));
+        }
+    }
+    if let Some(filename) = matches.value_of("region-bed") {
+        regions.extend(read_bed(filename));
+    }
+    regions
+}
+
+/// Read the regions of a BED file (path, start, end)
+pub fn read_bed(filename: &str) -> Vec<(String, u64, u64)> {
+    let file = File::open(filename).expect("Error: Unable to open BED file");
+    let reader = BufReader::new(file);
+    let mut regions = Vec::new();
+    for line in reader.lines() {
+        let line = line.unwrap();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() < 3 {
+            panic!("Error: BED line has less than 3 columns: {}", line);
+        }
+        regions.push((
+            fields[0].to_string(),
+            fields[1].parse().expect("Error: BED start is not numeric"),
+            fields[2].parse().expect("Error: BED end is not numeric"),
+        ));
+    }
+    regions
+}
+
+/// Parse a single region (path:start-end)
+pub fn parse_region(region: &str) -> (String, u64, u64) {
+    let (name, interval) = region
+        .rsplit_once(':')
+        .unwrap_or_else(|| panic!("Error: Region {} is not path:start-end", region));
+    let (start, end) = interval
+        .split_once('-')
+        .unwrap_or_else(|| panic!("Error: Region {} is not path:start-end", region));
+    (
+        name.to_string(),
+        start.parse().expect("Error: Region start is not numeric"),
+        end.parse().expect("Error: Region end is not numeric"),
+    )
+}
+
+pub fn region_name(region: &(String, u64, u64)) -> String {
+    format!("{}:{}-{}", region.0, region.1, region.2)
+}
+
+/// Restrict the graph to the nodes touched by the regions
+///
+/// Only nodes which overlap the path intervals are kept (induced subgraph). All paths are split at excluded nodes (same as extract), paths without any of them are removed.
+/// Fragments keep the name of their path, so they still belong to the same sample.
+/// Output is the start of each fragment on its path (same order as the paths)
+pub fn restrict_graph(graph: &mut Gfa<u32, (), ()>, regions: &[(String, u64, u64)]) -> Vec<u64> {
+    let nodes = region_nodes(graph, regions);
+    info!("Region: {} nodes", nodes.len());
+
+    let node_len = calc_node_len(graph);
+    let mut paths = Vec::new();
+    let mut starts = Vec::new();
+    for path in graph.paths.iter() {
+        for ((start, _), n, d) in path_fragments(path, &nodes, &node_len, 0) {
+            let mut fragment = path.clone();
+            fragment.nodes = n;
+            fragment.dir = d;
+            paths.push(fragment);
+            starts.push(start);
+        }
+    }
+    graph.paths = paths;
+    graph.segments.retain(|s| nodes.contains(&s.id));
+    graph
+        .links
+        .retain(|l| nodes.contains(&l.from) && nodes.contains(&l.to));
+    if graph.segments.is_empty() {
+        panic!("Error: No node found in the region(s)")
+    }
+    starts
+}
+
+/// All nodes which overlap the path intervals
+pub fn region_nodes(graph: &Gfa<u32, (), ()>, regions: &[(String, u64, u64)]) -> HashSet<u32> {
+    let mut nodes: HashSet<u32> = HashSet::new();
+    for (name, start, end) in regions.iter() {
+        let path = graph
+            .paths
+            .iter()
+            .find(|p| &p.name == name)
+            .unwrap_or_else(|| panic!("Error: Region path {} not found in graph file", name));
+        let mut pos: u64 = 0;
+        for n in path.nodes.iter() {
+            let size = graph.get_node_by_id(n).sequence.get_len() as u64;
+            if pos < *end && pos + size > *start {
+                nodes.insert(*n);
+            }
+            pos += size;
+            if pos >= *end {
+                break;
+            }
+        }
+    }
+    nodes
+}
+
+/// Run a subcommand on one or multiple regions
+///
+/// Multiple regions: the subcommand is run once for each region, all outputs (incl. additional files) are merged and each row is prefixed with the region.
+/// Files (suffix of the output name) in 'headers' have a header line, which is only reported once.
+/// Files in 'separate' are no tables (e.g. meta files) and are kept for each region (output.region0, output.region1, ...).
+/// Each region is written to its own temporary directory, only these files are merged.
+pub fn region_wrapper(
+    matches: &ArgMatches,
+    run: fn(&ArgMatches, &[(String, u64, u64)], &str),
+    headers: &[&str],
+    separate: &[&str],
+) {
+    let output = matches.value_of("output").unwrap();
+    let regions = read_regions(matches);
+    if regions.len() <= 1 {
+        run(matches, &regions, output);
+        return;
+    }
+
+    let tmp_dir = tempdir().expect("Error: Unable to create temporary directory");
+    let mut files: Vec<(String, Vec<(String, String)>)> = Vec::new();
+    for (i, region) in regions.iter().enumerate() {
+        info!("Region {}", region_name(region));
+        let region_dir = tmp_dir.path().join(format!("region{}", i));
+        fs::create_dir(&region_dir).expect("Error: Unable to create temporary directory");
+        let tmp_output = region_dir.join("output");
+        run(matches, &regions[i..i + 1], tmp_output.to_str().unwrap());
+        for (suffix, file) in region_files(&region_dir) {
+            if separate.contains(&suffix.as_str()) {
+                let kept = format!("{}.region{}{}", output, i, suffix);
+                fs::copy(&file, &kept).expect("Unable to copy file");
+                info!("Region {} kept in {}", region_name(region), kept);
+                continue;
+            }
+            match files.iter_mut().find(|x| x.0 == suffix) {
+                Some(x) => x.1.push((region_name(region), file)),
+                None => files.push((suffix, vec![(region_name(region), file)])),
+            }
+        }
+    }
+    for (suffix, region_files) in files.iter() {
+        let header = headers.contains(&suffix.as_str());
+        merge_region_files(region_files, &format!("{}{}", output, suffix), header);
+    }
+}
+
+/// All files written for a single region (temporary directory, output name is 'output')
+///
+/// Output is a vector of [suffix, filename]
+fn region_files(dir: &Path) -> Vec<(String, String)> {
+    let mut result = Vec::new();
+    for entry in fs::read_dir(dir).expect("Error: Unable to read temporary directory") {
+        let entry = entry.unwrap();
+        let filename = entry.file_name().to_str().unwrap().to_string();
+        if let Some(suffix) = filename.strip_prefix("output") {
+            result.push((
+                suffix.to_string(),
+                entry.path().to_str().unwrap().to_string(),
+            ));
+        }
+    }
+    result
+}
+
+/// Merge the output of all regions, add region as first column
+fn merge_region_files(data: &[(String, String)], filename: &str, header: bool) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    for (i, (region, file)) in data.iter().enumerate() {
+        let reader = BufReader::new(File::open(file).expect("Unable to open file"));
+        for (j, line) in reader.lines().enumerate() {
+            let line = line.unwrap();
+            if header && j == 0 {
+                if i == 0 {
+                    match line.strip_prefix('#') {
+                        Some(l) => writeln!(f, "#Region\t{}", l),
+                        None => writeln!(f, "Region\t{}", line),
+                    }
+                    .expect("Not able to write");
+                }
+                continue;
+            }
+            writeln!(f, "{}\t{}", region, line).expect("Not able to write");
+        }
+    }
+}
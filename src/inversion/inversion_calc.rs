@@ -0,0 +1,129 @@
+use gfa_reader::{Gfa, Path};
+use std::cmp::{max, min};
+use std::collections::{HashMap, HashSet};
+
+/// Candidate inversion relative to the reference path
+///
+/// Coordinates are 0-based, end exclusive (BED)
+#[derive(Debug, Clone)]
+pub struct Inversion {
+    pub start: u64,
+    pub end: u64,
+    pub ref_start: u64,
+    pub ref_end: u64,
+    pub nodes: usize,
+}
+
+/// Get the reference path by name
+pub fn get_reference<'a>(graph: &'a Gfa<u32, (), ()>, name: &str) -> &'a Path<u32, (), ()> {
+    match graph.paths.iter().find(|p| p.name == name) {
+        Some(path) => path,
+        None => panic!("Error: Reference path {} not found in graph file", name),
+    }
+}
+
+/// Orientation and position of each node in the reference path
+///
+/// Nodes traversed in both orientations by the reference are removed (no unique orientation)
+pub fn reference_orientation(
+    path: &Path<u32, (), ()>,
+    node_size: &Vec<u32>,
+) -> HashMap<u32, (bool, u64)> {
+    let mut result: HashMap<u32, (bool, u64)> = HashMap::new();
+    let mut ambiguous = HashSet::new();
+    let mut pos: u64 = 0;
+    for (node, dir) in path.nodes.iter().zip(path.dir.iter()) {
+        match result.get(node) {
+            Some((ref_dir, _)) => {
+                if ref_dir != dir {
+                    ambiguous.insert(*node);
+                }
+            }
+            None => {
+                result.insert(*node, (*dir, pos));
+            }
+        }
+        pos += node_size[*node as usize] as u64;
+    }
+    for node in ambiguous.iter() {
+        result.remove(node);
+    }
+    result
+}
+
+/// Main orientation of a path relative to the reference
+///
+/// True if most of the shared sequence [bp] is traversed in the opposite orientation (reverse-complemented contig)
+pub fn path_reversed(
+    path: &Path<u32, (), ()>,
+    reference: &HashMap<u32, (bool, u64)>,
+    node_size: &Vec<u32>,
+) -> bool {
+    let mut same: u64 = 0;
+    let mut opposite: u64 = 0;
+    for (node, dir) in path.nodes.iter().zip(path.dir.iter()) {
+        if let Some((ref_dir, _)) = reference.get(node) {
+            if dir == ref_dir {
+                same += node_size[*node as usize] as u64;
+            } else {
+                opposite += node_size[*node as usize] as u64;
+            }
+        }
+    }
+    opposite > same
+}
+
+/// Find runs of nodes traversed in the opposite orientation to the main orientation of the path
+///
+/// The main orientation is the bp-weighted majority of the shared nodes (see path_reversed), so a
+/// reverse-complemented contig is not an inversion by itself.
+/// Only nodes shared with the reference are considered. A run is stopped by a shared node
+/// in the main orientation, non-shared nodes are included in the run.
+pub fn find_inversions(
+    path: &Path<u32, (), ()>,
+    reference: &HashMap<u32, (bool, u64)>,
+    node_size: &Vec<u32>,
+) -> Vec<Inversion> {
+    let reversed = path_reversed(path, reference, node_size);
+    let mut result = Vec::new();
+    let mut current: Option<Inversion> = None;
+    let mut pos: u64 = 0;
+    for (node, dir) in path.nodes.iter().zip(path.dir.iter()) {
+        let size = node_size[*node as usize] as u64;
+        if let Some((ref_dir, ref_pos)) = reference.get(node) {
+            if (dir != ref_dir) != reversed {
+                match current.as_mut() {
+                    Some(inversion) => {
+                        inversion.end = pos + size;
+                        inversion.ref_start = min(inversion.ref_start, *ref_pos);
+                        inversion.ref_end = max(inversion.ref_end, ref_pos + size);
+                        inversion.nodes += 1;
+                    }
+                    None => {
+                        current = Some(Inversion {
+                            start: pos,
+                            end: pos + size,
+                            ref_start: *ref_pos,
+                            ref_end: ref_pos + size,
+                            nodes: 1,
+                        })
+                    }
+                }
+            } else if let Some(inversion) = current.take() {
+                result.push(inversion);
+            }
+        }
+        pos += size;
+    }
+    if let Some(inversion) = current {
+        result.push(inversion);
+    }
+    result
+}
+
+/// Summary of inversions (number, nodes, sequence)
+pub fn inversion_summary(inversions: &[Inversion]) -> (usize, usize, u64) {
+    let nodes = inversions.iter().map(|x| x.nodes).sum();
+    let seq = inversions.iter().map(|x| x.end - x.start).sum();
+    (inversions.len(), nodes, seq)
+}
@@ -0,0 +1,59 @@
+use crate::helpers::helper::calc_node_len;
+use crate::inversion::inversion_calc::{find_inversions, get_reference, reference_orientation};
+use crate::inversion::writer::{write_inversions, write_inversions_reference};
+use clap::ArgMatches;
+use gfa_reader::{check_numeric_gfafile, Gfa};
+use log::info;
+
+/// Main function for inversion subcommand
+///
+/// Find runs of nodes which are traversed in the opposite orientation than in the reference path
+pub fn inversion_main(matches: &ArgMatches) {
+    info!("Running 'gretl inversion'");
+    let mut sep = " ";
+    if matches.is_present("Pan-SN") {
+        sep = matches.value_of("Pan-SN").unwrap();
+    }
+
+    if check_numeric_gfafile(matches.value_of("gfa").unwrap()) {
+        let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
+        graph.walk_to_path(sep);
+        if graph.paths.is_empty() {
+            panic!("Error: No path found in graph file")
+        }
+        let output = matches.value_of("output").unwrap();
+        let reference_name = matches.value_of("reference").unwrap();
+
+        info!("Gfa file: {}", matches.value_of("gfa").unwrap());
+        info!("Output file: {}", output);
+        info!("Reference: {}", reference_name);
+
+        let node_size = calc_node_len(&graph);
+        let reference = get_reference(&graph, reference_name);
+        let orientation = reference_orientation(reference, &node_size);
+
+        let mut result = Vec::new();
+        for path in graph.paths.iter() {
+            if path.name != reference_name {
+                result.push((
+                    path.name.clone(),
+                    find_inversions(path, &orientation, &node_size),
+                ));
+            }
+        }
+        info!(
+            "Number of candidate inversions: {}",
+            result.iter().map(|x| x.1.len()).sum::<usize>()
+        );
+
+        info!("Writing to file");
+        write_inversions(&result, reference_name, output);
+        write_inversions_reference(
+            &result,
+            reference_name,
+            &(output.to_string() + ".reference.bed"),
+        );
+    } else {
+        panic!("Error: GFA file is not numeric");
+    }
+}
@@ -0,0 +1,3 @@
+pub mod inversion_calc;
+pub mod inversion_main;
+mod writer;
@@ -0,0 +1,57 @@
+use crate::inversion::inversion_calc::Inversion;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Write the inversions in BED format (coordinates of the path)
+///
+/// Columns: path, start, end, reference, reference start, reference end, nodes, sequence [bp]
+pub fn write_inversions(data: &Vec<(String, Vec<Inversion>)>, reference: &str, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    for (name, inversions) in data.iter() {
+        for x in inversions.iter() {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                name,
+                x.start,
+                x.end,
+                reference,
+                x.ref_start,
+                x.ref_end,
+                x.nodes,
+                x.end - x.start
+            )
+            .expect("Not able to write");
+        }
+    }
+}
+
+/// Write the inversions in BED format (coordinates of the reference)
+///
+/// Columns: reference, start, end, path, path start, path end, nodes, sequence [bp]
+pub fn write_inversions_reference(
+    data: &Vec<(String, Vec<Inversion>)>,
+    reference: &str,
+    filename: &str,
+) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    for (name, inversions) in data.iter() {
+        for x in inversions.iter() {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                reference,
+                x.ref_start,
+                x.ref_end,
+                name,
+                x.start,
+                x.end,
+                x.nodes,
+                x.end - x.start
+            )
+            .expect("Not able to write");
+        }
+    }
+}
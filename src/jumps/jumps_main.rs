@@ -0,0 +1,79 @@
+use crate::helpers::helper::calc_node_len;
+use crate::jumps::writer::write_jumps;
+use clap::ArgMatches;
+use gfa_reader::{check_numeric_compact_gfafile, Gfa, Path};
+use log::info;
+
+/// Main function for jump subcommand
+///
+/// Report all jumps (difference between node IDs of consecutive steps) bigger than a threshold in a BED file
+pub fn jumps_main(matches: &ArgMatches) {
+    info!("Running 'gretl jumps'");
+    let mut sep = " ";
+    if matches.is_present("Pan-SN") {
+        sep = matches.value_of("Pan-SN").unwrap();
+    }
+
+    let mut threshold: u32 = 20;
+    if matches.is_present("threshold") {
+        threshold = matches.value_of("threshold").unwrap().parse().unwrap();
+    }
+
+    let (numeric, sorted) = check_numeric_compact_gfafile(matches.value_of("gfa").unwrap());
+    if numeric {
+        if !sorted {
+            eprintln!("Error: The GFA file is not sorted. All 'jump' stats might be without sense.")
+        }
+        let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
+        graph.walk_to_path(sep);
+        if graph.paths.is_empty() {
+            panic!("Error: No path found in graph file")
+        }
+        let output = matches.value_of("output").unwrap();
+
+        info!("Gfa file: {}", matches.value_of("gfa").unwrap());
+        info!("Output file: {}", output);
+        info!("Threshold: {}", threshold);
+
+        let node_size = calc_node_len(&graph);
+        let mut result = Vec::new();
+        for path in graph.paths.iter() {
+            result.extend(path_jumps_bed(path, &node_size, threshold));
+        }
+        info!("Number of jumps: {}", result.len());
+
+        info!("Writing to file");
+        write_jumps(&result, output);
+    } else {
+        panic!("Error: GFA file is not numeric");
+    }
+}
+
+/// Find all jumps bigger than threshold in a path
+///
+/// Coordinates cover both nodes of the jump (start of the first node, end of the second node).
+/// Output is a vector of [path name, start, end, jump, (node1, dir1), (node2, dir2)]
+pub fn path_jumps_bed(
+    path: &Path<u32, (), ()>,
+    node_size: &Vec<u32>,
+    threshold: u32,
+) -> Vec<(String, u64, u64, u32, (u32, bool), (u32, bool))> {
+    let mut result = Vec::new();
+    let mut pos: u64 = 0;
+    for i in 0..path.nodes.len().saturating_sub(1) {
+        let (n1, n2) = (path.nodes[i], path.nodes[i + 1]);
+        let jump = (n2 as i64 - n1 as i64).unsigned_abs() as u32;
+        if jump > threshold {
+            result.push((
+                path.name.clone(),
+                pos,
+                pos + node_size[n1 as usize] as u64 + node_size[n2 as usize] as u64,
+                jump,
+                (n1, path.dir[i]),
+                (n2, path.dir[i + 1]),
+            ));
+        }
+        pos += node_size[n1 as usize] as u64;
+    }
+    result
+}
@@ -0,0 +1,2 @@
+pub mod jumps_main;
+mod writer;
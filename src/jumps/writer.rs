@@ -0,0 +1,25 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Write the jumps in BED format
+///
+/// Columns: path, start, end, jump, node1, dir1, node2, dir2
+pub fn write_jumps(data: &Vec<(String, u64, u64, u32, (u32, bool), (u32, bool))>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    for (name, start, end, jump, node1, node2) in data.iter() {
+        writeln!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            name,
+            start,
+            end,
+            jump,
+            node1.0,
+            if node1.1 { "+" } else { "-" },
+            node2.0,
+            if node2.1 { "+" } else { "-" }
+        )
+        .expect("Not able to write");
+    }
+}
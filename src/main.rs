@@ -1,32 +1,61 @@
 mod bootstrap;
+mod copy_number;
 mod core;
+mod edge_list;
+mod extract;
 mod feature;
 mod find;
 mod helpers;
 mod id2int;
+mod inversion;
+mod jumps;
 mod logging;
 mod node_list;
 mod nwindow;
 mod path;
 mod path_similarity;
+mod rarefaction;
+mod reference;
+mod select;
 mod sliding_window;
 mod stats;
 
 use crate::bootstrap::bootstrap_main::bootstrap_main;
+use crate::copy_number::copy_number_main::copy_number_main;
 use crate::core::core_main::core_main;
+use crate::edge_list::edge_list_main::edgelist_main;
+use crate::extract::extract_main::extract_main;
 use crate::feature::feature_main::feature_main;
 use crate::find::find_main::find_main;
+use crate::helpers::region::{read_regions, region_wrapper};
 use crate::id2int::id2int_main::id2int_main;
+use crate::inversion::inversion_main::inversion_main;
+use crate::jumps::jumps_main::jumps_main;
 use crate::logging::newbuilder;
 use crate::node_list::node_list_main::nodelist_main;
 use crate::nwindow::nwindow_main::nwindow_main;
 use crate::path::path_main::path_main;
 use crate::path_similarity::ps_main::ps_main;
+use crate::rarefaction::rarefaction_main::rarefaction_main;
+use crate::reference::reference_main::reference_main;
+use crate::select::select_main::select_main;
 use crate::sliding_window::sliding_window_main::window_main;
 use crate::stats::stats_main::stats_main;
 use clap::{App, AppSettings, Arg};
 
 fn main() {
+    // Region options (only for subcommands which support them)
+    let region_args = [
+        Arg::new("region")
+            .long("region")
+            .about("Restrict the analysis to these path regions (path:start-end, comma separated)")
+            .takes_value(true),
+        Arg::new("region-bed")
+            .long("region-bed")
+            .about("Restrict the analysis to the path regions in this BED file (path, start, end)")
+            .takes_value(true),
+    ];
+
     let matches = App::new("gretl")
         .setting(AppSettings::ArgRequiredElseHelp)
         .version("0.1.0")
@@ -37,6 +66,7 @@ fn main() {
         // Subcommand for normal stats for one graph
         .subcommand(App::new("stats")
             .about("Create statists about the graph or its path")
+            .args(&region_args)
             .arg(Arg::new("gfa")
                 .short('g')
                 .long("gfa")
@@ -60,6 +90,14 @@ fn main() {
                 .long("bins")
                 .about("Size of bins. Example: Format 10,20,30 -> (0-10, 11-20, 30+)[default: 1,50,100,1000]")
                 .takes_value(true))
+            .arg(Arg::new("reference")
+                .long("reference")
+                .about("Reference path. Report candidate inversions (relative to the reference) in path stats")
+                .takes_value(true))
+            .arg(Arg::new("jump-threshold")
+                .long("jump-threshold")
+                .about("Report number of jumps bigger than this threshold [default: 20]")
+                .takes_value(true))
             .arg(Arg::new("path")
                 .short('p')
                 .long("path")
@@ -70,6 +108,7 @@ fn main() {
 
         .subcommand(App::new("bootstrap")
             .about("Bootstrap approach")
+            .args(&region_args)
             .help_heading("Input options")
             .arg(Arg::new("gfa")
                 .short('g')
@@ -96,6 +135,23 @@ fn main() {
                 .long("nodes")
                 .about("Run bootstrap only on these nodes")
                 .takes_value(true))
+            .arg(Arg::new("bed")
+                .long("bed")
+                .about("Run bootstrap only on the nodes which overlap these regions (BED file: path, start, end), e.g. on a reference path. Combined with --nodes: nodes in both")
+                .takes_value(true))
+            .arg(Arg::new("order")
+                .long("order")
+                .about("Cumulative growth in this order instead of random combinations. File with one sample per line, optional second column (tab separated) is the group (groups are added at once)")
+                .takes_value(true))
+            .arg(Arg::new("groups")
+                .long("groups")
+                .about("Stratified bootstrap: sample-to-group table (sample, group; tab separated)")
+                .takes_value(true))
+            .arg(Arg::new("strata")
+                .long("strata")
+                .about("Samples per group in a stratified bootstrap: proportional to the group size or equal for all groups [default: proportional]")
+                .possible_values(&["proportional", "equal"])
+                .takes_value(true))
 
 
             .help_heading("Modifications")
@@ -107,10 +163,23 @@ fn main() {
                 .long("level")
                 .about("Calculate a specific level")
                 .takes_value(true))
+            .arg(Arg::new("metric")
+                .long("metric")
+                .about("Count nodes or edges by the number of samples (similarity, edge) or traversals (depth, edge-depth) [default: similarity]")
+                .possible_values(&["similarity", "depth", "edge", "edge-depth"])
+                .takes_value(true))
             .arg(Arg::new("number")
                 .long("number")
                 .about("How many bootstraps do you want to run")
                 .takes_value(true))
+            .arg(Arg::new("exhaustive")
+                .long("exhaustive")
+                .about("Use all combinations if there are not more than this number of them [default: --number]")
+                .takes_value(true))
+            .arg(Arg::new("seed")
+                .long("seed")
+                .about("Seed for the random combinations (reproducible runs), reported in the meta file [default: random]")
+                .takes_value(true))
 
             .help_heading("Output options")
             .arg(Arg::new("output")
@@ -125,9 +194,62 @@ fn main() {
 
 
 
+        .subcommand(App::new("rarefaction")
+            .about("Exact rarefaction: expected pan, core and shell size for each number of samples")
+            .arg(Arg::new("gfa")
+                .short('g')
+                .long("gfa")
+                .about("Input GFA file")
+                .takes_value(true)
+                .required(true))
+            .arg(Arg::new("Pan-SN")
+                .long("pansn")
+                .about("Separate by first entry in Pan-SN spec")
+                .takes_value(true))
+            .arg(Arg::new("output")
+                .short('o')
+                .long("output")
+                .about("Output")
+                .takes_value(true)
+                .required(true)))
+
+        .subcommand(App::new("select")
+            .about("Greedy minimal set of samples which covers a fraction of the graph sequence")
+            .arg(Arg::new("gfa")
+                .short('g')
+                .long("gfa")
+                .about("Input GFA file")
+                .takes_value(true)
+                .required(true))
+            .arg(Arg::new("Pan-SN")
+                .long("pansn")
+                .about("Separate by first entry in Pan-SN spec")
+                .takes_value(true))
+            .arg(Arg::new("coverage")
+                .short('c')
+                .long("coverage")
+                .about("Stop when this percentage of the graph sequence is covered [default: 100]")
+                .takes_value(true))
+            .arg(Arg::new("required")
+                .short('r')
+                .long("required")
+                .about("Samples which are always selected first (comma separated)")
+                .takes_value(true))
+            .arg(Arg::new("depth")
+                .short('d')
+                .long("depth")
+                .about("Weight each node by its depth (sequence x depth)"))
+            .arg(Arg::new("output")
+                .short('o')
+                .long("output")
+                .about("Output")
+                .takes_value(true)
+                .required(true)))
+
         // Subcommand for similarity level
         .subcommand(App::new("core")
             .about("Graph similarity statistics")
+            .args(&region_args)
             .arg(Arg::new("gfa")
                 .short('g')
                 .long("gfa")
@@ -137,9 +259,17 @@ fn main() {
             .arg(Arg::new("statistics")
                 .short('s')
                 .long("stats")
-                .about("similarity, depth, node degree")
+                .about("similarity, depth, nd (node degree), edge (edge similarity)")
                 .default_value("similarity")
                 .takes_value(true))
+            .arg(Arg::new("classify")
+                .short('c')
+                .long("classify")
+                .about("Additionally report nodes and sequence for each class (core, shell, cloud, ...) for the graph and each sample"))
+            .arg(Arg::new("classes")
+                .long("classes")
+                .about("Class definitions: name and minimum number of samples, absolute or in percent (comma separated) [default: core:100%,soft-core:95%,shell:2,cloud:1]")
+                .takes_value(true))
             .arg(Arg::new("output")
                 .short('o')
                 .long("output")
@@ -155,12 +285,19 @@ fn main() {
         // Subcommand for detailed similarity
         .subcommand(App::new("ps")
             .about("Detailed similarity information for each path")
+            .args(&region_args)
             .arg(Arg::new("gfa")
                 .short('g')
                 .long("gfa")
                 .about("Input GFA file")
                 .takes_value(true)
                 .required(true))
+            .arg(Arg::new("statistics")
+                .short('s')
+                .long("stats")
+                .about("similarity, depth, nd (node degree), edge (edge similarity)")
+                .default_value("similarity")
+                .takes_value(true))
             .arg(Arg::new("output")
                 .short('o')
                 .long("output")
@@ -200,6 +337,7 @@ fn main() {
 
         .subcommand(App::new("window")
             .about("Sliding window analysis (path-centric)")
+            .args(&region_args)
             .arg(Arg::new("gfa")
                 .short('g')
                 .long("gfa")
@@ -226,9 +364,34 @@ fn main() {
                 .short('n')
                 .long("node")
                 .about("Window on node level ([default: off] -> on sequence)"))
+            .arg(Arg::new("mode")
+                .long("mode")
+                .about("Window mode: 'bp', 'node' (sliding), 'distinct' (distinct nodes), 'graph-bp' (bp of distinct nodes), 'bubble' (min. bp, end at bubble boundary) [default: bp]")
+                .takes_value(true))
             .arg(Arg::new("metric")
                 .long("metric")
-                .about("Metrics. Example: 'similarity', 'nodesize', 'depth' [default: similarity]")
+                .about("Metrics, comma separated. Example: 'similarity', 'nodesize', 'depth', 'nd', 'nd_in', 'nd_out', 'forward', 'reverse', 'private' [default: similarity]")
+                .takes_value(true))
+            .arg(Arg::new("aggregation")
+                .short('a')
+                .long("aggregation")
+                .about("Aggregations, comma separated. Example: 'mean', 'median', 'min', 'max', 'fraction' [default: mean]")
+                .takes_value(true))
+            .arg(Arg::new("threshold")
+                .short('t')
+                .long("threshold")
+                .about("Threshold for the 'fraction' aggregation (fraction of values above the threshold) [default: 1]")
+                .takes_value(true))
+            .arg(Arg::new("bed")
+                .long("bed")
+                .about("Report one window per line (BED): path, start, end, number of nodes, bp, value"))
+            .arg(Arg::new("bedgraph")
+                .long("bedgraph")
+                .about("Project the metric on the path coordinates (bedGraph, one record per stretch of nodes with the same value) instead of windows"))
+            .arg(Arg::new("path")
+                .short('p')
+                .long("path")
+                .about("Only report this path (bedGraph)")
                 .takes_value(true)))
 
 
@@ -282,6 +445,7 @@ fn main() {
 
 
         .subcommand(App::new("node-list")
+            .args(&region_args)
             .arg(Arg::new("gfa")
                 .short('g')
                 .long("gfa")
@@ -304,7 +468,59 @@ fn main() {
                 .short('f')
                 .long("feature")
                 .takes_value(true)
-                .about("Name the features you need. If nothing is used, report everything. Example -f Length, Core")))
+                .about("Name the features you need. If nothing is used, report everything. Example -f Length, Core"))
+            .arg(Arg::new("classes")
+                .long("classes")
+                .about("Class definitions for the Class feature: name and minimum number of samples, absolute or in percent (comma separated) [default: core:100%,soft-core:95%,shell:2,cloud:1]")
+                .takes_value(true)))
+
+
+
+
+        .subcommand(App::new("copy-number")
+            .about("Copy number of each node in each sample (node x sample matrix)")
+            .arg(Arg::new("gfa")
+                .short('g')
+                .long("gfa")
+                .about("Input GFA file")
+                .takes_value(true)
+                .required(true))
+            .arg(Arg::new("output")
+                .short('o')
+                .long("output")
+                .about("Output")
+                .takes_value(true)
+                .required(true))
+            .arg(Arg::new("Pan-SN")
+                .short('s')
+                .long("pansn")
+                .about("Separate by first entry in Pan-SN spec")
+                .takes_value(true)))
+
+        .subcommand(App::new("edge-list")
+            .arg(Arg::new("gfa")
+                .short('g')
+                .long("gfa")
+                .about("Input GFA file")
+                .takes_value(true)
+                .required(true))
+            .arg(Arg::new("output")
+                .short('o')
+                .long("output")
+                .about("Output")
+                .takes_value(true)
+                .required(true))
+            .arg(Arg::new("Pan-SN")
+                .short('s')
+                .long("pansn")
+                .about("Separate by first entry in Pan-SN spec")
+                .takes_value(true))
+            .about("Some information about each edge (depth, similarity, unused and missing links)")
+            .arg(Arg::new("Features")
+                .short('f')
+                .long("feature")
+                .takes_value(true)
+                .about("Name the features you need. If nothing is used, report everything. Example -f Depth,Similarity")))
 
 
 
@@ -371,6 +587,11 @@ fn main() {
                 .about("Maximum node depth")
                 .takes_value(true)
             )
+            .arg(Arg::new("bi-oriented")
+                .short('b')
+                .long("bi-oriented")
+                .about("Only nodes which are traversed in both orientations (forward and reverse)")
+            )
         )
 
 
@@ -418,6 +639,80 @@ fn main() {
             )
 
 
+        )
+        .subcommand(App::new("extract")
+            .about("Extract a subgraph (GFA) of selected nodes (node list, path list, region and/or feature filter)")
+            .args(&region_args)
+            .arg(Arg::new("gfa")
+                .short('g')
+                .long("gfa")
+                .about("Input GFA file")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("output")
+                .short('o')
+                .long("output")
+                .about("Output file (GFA)")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("Pan-SN")
+                .long("pansn")
+                .about("Separator for Pan-SN spec (only used for walks) [default: #]")
+                .takes_value(true)
+            )
+            .arg(Arg::new("nodes")
+                .long("nodes")
+                .about("File with node IDs, one per line (e.g. output of 'gretl feature')")
+                .takes_value(true)
+            )
+            .arg(Arg::new("paths")
+                .long("paths")
+                .about("File with path names, one per line (e.g. output of 'gretl path'). Select all nodes of these paths")
+                .takes_value(true)
+            )
+            .arg(Arg::new("min-len")
+                .short('l')
+                .long("min-len")
+                .about("Minimum length")
+                .takes_value(true)
+            )
+            .arg(Arg::new("max-len")
+                .short('L')
+                .long("max-len")
+                .about("Maximum node length")
+                .takes_value(true)
+            )
+            .arg(Arg::new("min-degree")
+                .short('n')
+                .long("min-degree")
+                .about("Minimum degree")
+                .takes_value(true)
+            )
+            .arg(Arg::new("max-degree")
+                .short('N')
+                .long("max-degree")
+                .about("Maximum node degree")
+                .takes_value(true)
+            )
+            .arg(Arg::new("min-depth")
+                .short('d')
+                .long("min-depth")
+                .about("Minimum depth")
+                .takes_value(true)
+            )
+            .arg(Arg::new("max-depth")
+                .short('D')
+                .long("max-depth")
+                .about("Maximum node depth")
+                .takes_value(true)
+            )
+            .arg(Arg::new("bi-oriented")
+                .short('b')
+                .long("bi-oriented")
+                .about("Only nodes which are traversed in both orientations (forward and reverse)")
+            )
         )
         .subcommand(App::new("find")
             .about("Find features in the graph and return a BED file for further analysis")
@@ -451,32 +746,160 @@ fn main() {
             )
         )
 
+        .subcommand(App::new("jumps")
+            .about("Report large node ID jumps along the paths in a BED file")
+            .arg(Arg::new("gfa")
+                .short('g')
+                .long("gfa")
+                .about("Input GFA file")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("output")
+                .short('o')
+                .long("output")
+                .about("Output file (BED)")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("Pan-SN")
+                .long("pansn")
+                .about("Separator for Pan-SN spec (only used for walks)")
+                .takes_value(true)
+            )
+            .arg(Arg::new("threshold")
+                .short('t')
+                .long("threshold")
+                .about("Report jumps bigger than this threshold [default: 20]")
+                .takes_value(true)
+            )
+        )
+        .subcommand(App::new("inversion")
+            .about("Find candidate inversions relative to a reference path (BED)")
+            .arg(Arg::new("gfa")
+                .short('g')
+                .long("gfa")
+                .about("Input GFA file")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("output")
+                .short('o')
+                .long("output")
+                .about("Output file (BED)")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("reference")
+                .short('r')
+                .long("reference")
+                .about("Name of the reference path")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("Pan-SN")
+                .long("pansn")
+                .about("Separator for Pan-SN spec (only used for walks)")
+                .takes_value(true)
+            )
+        )
+        .subcommand(App::new("reference")
+            .about("Compare each sample to a reference path or sample (shared, inserted and missing sequence)")
+            .arg(Arg::new("gfa")
+                .short('g')
+                .long("gfa")
+                .about("Input GFA file")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("output")
+                .short('o')
+                .long("output")
+                .about("Output file")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("reference")
+                .short('r')
+                .long("reference")
+                .about("Name of the reference path or sample (with --pansn)")
+                .takes_value(true)
+                .required(true)
+            )
+            .arg(Arg::new("Pan-SN")
+                .long("pansn")
+                .about("Separate by first entry in Pan-SN spec")
+                .takes_value(true)
+            )
+        )
+
         .get_matches();
 
     // Read the graph
     newbuilder(&matches);
 
     if let Some(matches) = matches.subcommand_matches("core") {
-        core_main(matches);
+        region_wrapper(
+            matches,
+            core_main,
+            &["", ".private.txt", ".classes.txt", ".classes.samples.txt"],
+            &[],
+        );
     } else if let Some(matches) = matches.subcommand_matches("bootstrap") {
-        bootstrap_main(matches);
+        region_wrapper(
+            matches,
+            bootstrap_main,
+            &[
+                "",
+                ".summary.txt",
+                ".combinations.txt",
+                ".heaps.txt",
+                ".groups.txt",
+            ],
+            &[".meta"],
+        );
+    } else if let Some(matches) = matches.subcommand_matches("rarefaction") {
+        rarefaction_main(matches);
+    } else if let Some(matches) = matches.subcommand_matches("select") {
+        select_main(matches);
     } else if let Some(matches) = matches.subcommand_matches("stats") {
-        stats_main(matches);
+        // YAML output can not be merged
+        if matches.is_present("YAML") && read_regions(matches).len() > 1 {
+            panic!("Error: Multiple regions are not supported with YAML output (-y)")
+        }
+        region_wrapper(matches, stats_main, &[""], &[]);
     } else if let Some(matches) = matches.subcommand_matches("id2int") {
         id2int_main(matches);
     } else if let Some(matches) = matches.subcommand_matches("ps") {
-        ps_main(matches);
+        region_wrapper(matches, ps_main, &[""], &[]);
     } else if let Some(matches) = matches.subcommand_matches("node-list") {
-        nodelist_main(matches);
+        region_wrapper(matches, nodelist_main, &[""], &[]);
+    } else if let Some(matches) = matches.subcommand_matches("copy-number") {
+        copy_number_main(matches);
+    } else if let Some(matches) = matches.subcommand_matches("edge-list") {
+        edgelist_main(matches);
     } else if let Some(matches) = matches.subcommand_matches("window") {
-        window_main(matches);
+        let headers: &[&str] = if matches.is_present("bed") {
+            &[""]
+        } else {
+            &[]
+        };
+        region_wrapper(matches, window_main, headers, &[]);
     } else if let Some(matches) = matches.subcommand_matches("feature") {
         feature_main(matches);
     } else if let Some(matches) = matches.subcommand_matches("path") {
         path_main(matches);
     } else if let Some(matches) = matches.subcommand_matches("nwindow") {
         nwindow_main(matches);
+    } else if let Some(matches) = matches.subcommand_matches("extract") {
+        extract_main(matches);
     } else if let Some(matches) = matches.subcommand_matches("find") {
         find_main(matches);
+    } else if let Some(matches) = matches.subcommand_matches("jumps") {
+        jumps_main(matches);
+    } else if let Some(matches) = matches.subcommand_matches("inversion") {
+        inversion_main(matches);
+    } else if let Some(matches) = matches.subcommand_matches("reference") {
+        reference_main(matches);
     }
 }
@@ -1,3 +1,5 @@
+use crate::helpers::classes::DEFAULT_CLASSES;
+use crate::helpers::region::restrict_graph;
 use crate::node_list::wrapper::wrapper_node;
 use clap::ArgMatches;
 use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
@@ -5,7 +7,7 @@ use log::info;
 
 
 /// Main function for node list
-pub fn nodelist_main(matches: &ArgMatches) {
+pub fn nodelist_main(matches: &ArgMatches, regions: &[(String, u64, u64)], output: &str) {
     info!("Running 'gretl node-list'");
     let mut sep = " ";
     if matches.is_present("Pan-SN") {
@@ -16,6 +18,9 @@ pub fn nodelist_main(matches: &ArgMatches) {
         // Parse GFA file + Wrapper
         let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
         graph.walk_to_path(sep);
+        if !regions.is_empty() {
+            restrict_graph(&mut graph, regions);
+        }
         if graph.paths.is_empty() {
             panic!("Error: No path found in graph file")
         }
@@ -23,8 +28,15 @@ pub fn nodelist_main(matches: &ArgMatches) {
         let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
 
         // Other inputs
-        let output = matches.value_of("output").unwrap();
-        let splits = vec!["Core", "Length", "Depth", "Core", "ND"];
+        let splits = vec![
+            "Core",
+            "Length",
+            "Depth",
+            "Core",
+            "ND",
+            "Orientation",
+            "Class",
+        ];
         let mut split_vec = Vec::new();
         if matches.is_present("Features") {
             split_vec = matches.value_of("Features").unwrap().split(',').collect();
@@ -45,7 +57,8 @@ pub fn nodelist_main(matches: &ArgMatches) {
 
         info!("Running wrapper + writing direclty to file");
         // This wrapper also writes data to a file
-        wrapper_node(&graph, &wrapper, output, final_features);
+        let classes = matches.value_of("classes").unwrap_or(DEFAULT_CLASSES);
+        wrapper_node(&graph, &wrapper, output, final_features, classes);
         info!("Finished writing to file");
     } else {
         panic!("Error: GFA file is not numeric");
@@ -1,5 +1,8 @@
-use crate::helpers::helper::{calc_depth, calc_node_degree, calc_node_len, calc_similarity};
-use crate::node_list::writer::{make_buffer, write_header, write_list};
+use crate::helpers::classes::{classify, parse_classes};
+use crate::helpers::helper::{
+    calc_depth, calc_node_degree, calc_node_len, calc_orientation, calc_similarity,
+};
+use crate::node_list::writer::{make_buffer, write_header, write_list, write_list_str};
 use gfa_reader::{Gfa, Pansn};
 
 
@@ -10,6 +13,7 @@ pub fn wrapper_node(
     wrapper: &Pansn<u32, (), ()>,
     filename: &str,
     what: Vec<&str>,
+    classes: &str,
 ) {
     let paths = wrapper.get_path_genome();
 
@@ -25,6 +29,17 @@ pub fn wrapper_node(
         let core = calc_similarity(&paths, graph);
         write_list(("Core", &core), &mut ff, &po);
     }
+    if what.contains(&"Class") {
+        let classes = parse_classes(classes, paths.len());
+        let class: Vec<String> = calc_similarity(&paths, graph)
+            .iter()
+            .map(|x| match classify(*x, &classes) {
+                Some(i) => classes[i].0.clone(),
+                None => "NA".to_string(),
+            })
+            .collect();
+        write_list_str(("Class", &class), &mut ff, &po);
+    }
     if what.contains(&"Depth") {
         let depth2 = calc_depth(&paths, graph);
         write_list(("Depth", &depth2), &mut ff, &po);
@@ -35,6 +50,11 @@ pub fn wrapper_node(
         write_list(("ND_out", &node_in), &mut ff, &po);
         write_list(("ND_total", &node_total), &mut ff, &po);
     }
+    if what.contains(&"Orientation") {
+        let (forward, reverse) = calc_orientation(&paths, graph);
+        write_list(("Forward", &forward), &mut ff, &po);
+        write_list(("Reverse", &reverse), &mut ff, &po);
+    }
 }
 
 pub fn get_zero_vec(size: &Vec<u32>) -> Vec<bool> {
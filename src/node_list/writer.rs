@@ -29,3 +29,15 @@ pub fn write_list(data: (&str, &Vec<u32>), f: &mut BufWriter<File>, ko: &Vec<boo
     }
     writeln!(f).expect("hilfe");
 }
+
+/// Write a row of names (e.g. class)
+pub fn write_list_str(data: (&str, &Vec<String>), f: &mut BufWriter<File>, ko: &Vec<bool>) {
+    write!(f, "{}\t", data.0).expect("hilfe");
+
+    for (x, i) in data.1.iter().zip(ko.iter()) {
+        if *i {
+            write!(f, "{}\t", x).expect("hilfe");
+        }
+    }
+    writeln!(f).expect("hilfe");
+}
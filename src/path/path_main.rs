@@ -37,7 +37,7 @@ pub fn path_runner(
     wrapper: &Pansn<u32, (), ()>,
     haplo: bool,
 ) -> Vec<String> {
-    let f = path_stats_wrapper(graph, wrapper, haplo);
+    let f = path_stats_wrapper(graph, wrapper, haplo, None, None);
 
     let mut result = Vec::with_capacity(f.len());
 
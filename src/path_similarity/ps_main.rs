@@ -1,21 +1,35 @@
-use crate::path_similarity::stats::accession2level;
-use crate::path_similarity::writer_test::write_ps;
+use crate::helpers::graphs::get_stats;
+use crate::helpers::helper::calc_edge_similarity;
+use crate::helpers::region::restrict_graph;
+use crate::path_similarity::stats::{accession2level, accession2level_edges};
+use crate::path_similarity::writer_test::{write_ps, write_ps_edges};
 use clap::ArgMatches;
 use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
 
 /// Main function for path related stats
-pub fn ps_main(matches: &ArgMatches) {
+pub fn ps_main(matches: &ArgMatches, regions: &[(String, u64, u64)], output: &str) {
     if check_numeric_gfafile(matches.value_of("gfa").unwrap()) {
         let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
         graph.walk_to_path("#");
+        if !regions.is_empty() {
+            restrict_graph(&mut graph, regions);
+        }
         if graph.paths.is_empty() {
             panic!("Error: No path found in graph file")
         }
         let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, " ");
-        let data = accession2level(&graph, &wrapper);
-        let output = matches.value_of("output").unwrap();
 
-        write_ps(&data, output);
+        // Which kind of stats
+        let kind = matches.value_of("statistics").unwrap();
+        if kind == "edge" {
+            let stats = calc_edge_similarity(&wrapper.get_path_genome());
+            let data = accession2level_edges(&wrapper, &stats);
+            write_ps_edges(&data, output);
+        } else {
+            let stats = get_stats(&wrapper, &graph, kind);
+            let data = accession2level(&graph, &wrapper, &stats);
+            write_ps(&data, output);
+        }
     } else {
         panic!("Error: GFA file is not numeric");
     }
@@ -1,13 +1,16 @@
-use crate::helpers::helper::calc_similarity;
+use crate::helpers::helper::path_edges;
 use gfa_reader::{Gfa, Pansn};
+use std::collections::HashMap;
 
 /// Compute the amount of sequence in each similarity level
+///
+/// Level is defined by the provided node statistic (e.g. similarity, depth)
 pub fn accession2level(
     graph: &Gfa<u32, (), ()>,
     wrapper: &Pansn<u32, (), ()>,
+    cores: &Vec<u32>,
 ) -> Vec<(String, Vec<(u32, u32)>)> {
     let paths = wrapper.get_path_genome();
-    let cores = calc_similarity(&paths, graph);
     let metric_maxval = cores.iter().max().unwrap();
     let mut res = Vec::new();
 
@@ -30,3 +33,26 @@ pub fn accession2level(
 
     res
 }
+
+/// Compute the amount of edges in each (edge) similarity level
+pub fn accession2level_edges(
+    wrapper: &Pansn<u32, (), ()>,
+    cores: &HashMap<u64, u32>,
+) -> Vec<(String, Vec<u32>)> {
+    let paths = wrapper.get_path_genome();
+    let metric_maxval = cores.values().max().unwrap_or(&0);
+    let mut res = Vec::new();
+
+    for (name, p) in paths.iter() {
+        let mut core_data: Vec<u32> = vec![0; *metric_maxval as usize + 1];
+        let mut ll: Vec<u64> = p.iter().flat_map(|k| path_edges(k)).collect();
+        ll.sort();
+        ll.dedup();
+        for x in ll.iter() {
+            core_data[cores[x] as usize] += 1;
+        }
+        res.push((name.clone(), core_data));
+    }
+
+    res
+}
@@ -31,3 +31,20 @@ pub fn write_ps(data: &Vec<(String, Vec<(u32, u32)>)>, filename: &str) {
             .expect("Not able to write");
     }
 }
+
+/// Write path similarity data (edges) to a file
+pub fn write_ps_edges(data: &Vec<(String, Vec<u32>)>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    let f1 = data[0].1.len();
+
+    let fk: Vec<String> = (0..f1)
+        .map(|n| "Edge:".to_string() + &n.to_string())
+        .collect();
+    writeln!(f, "Accession\t{}", fk.join("\t")).expect("Not able to write");
+
+    for entry in data.iter() {
+        let test1: Vec<String> = entry.1.iter().map(|n| n.to_string()).collect();
+        writeln!(f, "{}\t{}", entry.0, test1.join("\t")).expect("Not able to write");
+    }
+}
@@ -0,0 +1,3 @@
+pub mod rarefaction_calc;
+pub mod rarefaction_main;
+pub mod writer;
@@ -0,0 +1,86 @@
+use std::cmp::min;
+
+/// ln(i!) for all i in 0..=n
+pub fn ln_factorials(n: usize) -> Vec<f64> {
+    let mut result = vec![0.0; n + 1];
+    for i in 1..=n {
+        result[i] = result[i - 1] + (i as f64).ln();
+    }
+    result
+}
+
+/// ln of the binomial coefficient (n choose k)
+fn ln_choose(ln_fact: &[f64], n: usize, k: usize) -> f64 {
+    ln_fact[n] - ln_fact[k] - ln_fact[n - k]
+}
+
+/// Hypergeometric probability
+///
+/// Probability that a node present in c of n samples is present in exactly k samples of a random subset of size m
+pub fn hypergeometric(ln_fact: &[f64], n: usize, c: usize, m: usize, k: usize) -> f64 {
+    if k > c || k > m || m - k > n - c {
+        return 0.0;
+    }
+    (ln_choose(ln_fact, c, k) + ln_choose(ln_fact, n - c, m - k) - ln_choose(ln_fact, n, m)).exp()
+}
+
+/// Number of nodes and sequence [bp] for each similarity level
+///
+/// Index is the similarity level (0..=n)
+pub fn similarity_distribution(
+    similarity: &Vec<u32>,
+    node_len: &Vec<u32>,
+    n: usize,
+) -> Vec<(u64, u64)> {
+    let mut result = vec![(0, 0); n + 1];
+    for (i, level) in similarity.iter().enumerate() {
+        if *level > 0 && node_len[i] > 0 {
+            result[*level as usize].0 += 1;
+            result[*level as usize].1 += node_len[i] as u64;
+        }
+    }
+    result
+}
+
+/// Exact rarefaction (expected values over all subsets)
+///
+/// For each subset size m (1..=n): expected number of nodes and sequence [bp] on each similarity level k (1..=m)
+/// Output is a vector of [m, vec of (nodes, bp)], the level vector starts with k = 1
+pub fn rarefaction(distribution: &[(u64, u64)], n: usize) -> Vec<(usize, Vec<(f64, f64)>)> {
+    let ln_fact = ln_factorials(n);
+    let mut result = Vec::with_capacity(n);
+    for m in 1..=n {
+        let mut levels = vec![(0.0, 0.0); m];
+        for (c, (nodes, bp)) in distribution.iter().enumerate().skip(1) {
+            if *nodes == 0 {
+                continue;
+            }
+            for k in 1..=min(c, m) {
+                let p = hypergeometric(&ln_fact, n, c, m, k);
+                levels[k - 1].0 += *nodes as f64 * p;
+                levels[k - 1].1 += *bp as f64 * p;
+            }
+        }
+        result.push((m, levels));
+    }
+    result
+}
+
+/// Summarize the levels of one subset size
+///
+/// - Pan: present in at least one sample
+/// - Core: present in all samples
+/// - Shell: present in more than one, but not all samples
+/// - Private: present in a single sample (only if m > 1)
+///
+/// Output: [pan, core, shell, private], each (nodes, bp)
+pub fn rarefaction_summary(m: usize, levels: &[(f64, f64)]) -> [(f64, f64); 4] {
+    let sum = |x: &[(f64, f64)]| x.iter().fold((0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1));
+    let pan = sum(levels);
+    let core = levels[m - 1];
+    if m == 1 {
+        return [pan, core, (0.0, 0.0), (0.0, 0.0)];
+    }
+    let shell = sum(&levels[1..m - 1]);
+    [pan, core, shell, levels[0]]
+}
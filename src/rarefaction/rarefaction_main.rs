@@ -0,0 +1,60 @@
+use crate::helpers::heaps::heaps_law;
+use crate::helpers::helper::{calc_node_len, calc_similarity};
+use crate::rarefaction::rarefaction_calc::{
+    rarefaction, rarefaction_summary, similarity_distribution,
+};
+use crate::rarefaction::writer::{write_heaps, write_rarefaction, write_rarefaction_levels};
+use clap::ArgMatches;
+use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
+use log::info;
+
+/// Main function for rarefaction subcommand
+///
+/// Exact expected pan, core and shell size for each number of samples (no random sampling)
+pub fn rarefaction_main(matches: &ArgMatches) {
+    info!("Running 'gretl rarefaction'");
+    let mut sep = " ";
+    if matches.is_present("Pan-SN") {
+        sep = matches.value_of("Pan-SN").unwrap();
+    }
+
+    if check_numeric_gfafile(matches.value_of("gfa").unwrap()) {
+        let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
+        graph.walk_to_path(sep);
+        if graph.paths.is_empty() {
+            panic!("Error: No path found in graph file")
+        }
+        let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
+        let output = matches.value_of("output").unwrap();
+
+        info!("Gfa file: {}", matches.value_of("gfa").unwrap());
+        info!("Output file: {}", output);
+
+        let paths = wrapper.get_path_genome();
+        let n = paths.len();
+        info!("Number of samples: {}", n);
+
+        let similarity = calc_similarity(&paths, &graph);
+        let node_len = calc_node_len(&graph);
+        let distribution = similarity_distribution(&similarity, &node_len, n);
+
+        info!("Calculating rarefaction");
+        let data = rarefaction(&distribution, n);
+
+        info!("Writing to file");
+        write_rarefaction(&data, output);
+        write_rarefaction_levels(&data, &(output.to_string() + ".levels.txt"));
+
+        info!("Fitting Heaps' law");
+        let curves: Vec<(usize, f64, f64, f64, f64)> = data
+            .iter()
+            .map(|(m, levels)| {
+                let summary = rarefaction_summary(*m, levels);
+                (*m, summary[0].0, summary[0].1, summary[1].0, summary[1].1)
+            })
+            .collect();
+        write_heaps(&heaps_law(&curves), &(output.to_string() + ".heaps.txt"));
+    } else {
+        panic!("Error: GFA file is not numeric");
+    }
+}
@@ -0,0 +1,78 @@
+use crate::helpers::heaps::PowerLaw;
+use crate::rarefaction::rarefaction_calc::rarefaction_summary;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Write the expected pan, core, shell and private size for each number of samples
+pub fn write_rarefaction(data: &Vec<(usize, Vec<(f64, f64)>)>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    writeln!(
+        f,
+        "Size\tPan#Node\tCore#Node\tShell#Node\tPrivate#Node\tPan[bp]\tCore[bp]\tShell[bp]\tPrivate[bp]"
+    )
+    .expect("Not able to write");
+    for (m, levels) in data.iter() {
+        let summary = rarefaction_summary(*m, levels);
+        let nodes: Vec<String> = summary.iter().map(|x| x.0.to_string()).collect();
+        let bp: Vec<String> = summary.iter().map(|x| x.1.to_string()).collect();
+        writeln!(f, "{}\t{}\t{}", m, nodes.join("\t"), bp.join("\t")).expect("Not able to write");
+    }
+}
+
+/// Write the expected number of nodes and sequence on each similarity level
+///
+/// Same layout as the bootstrap output (Node:i, Seq:i), missing levels are empty
+pub fn write_rarefaction_levels(data: &Vec<(usize, Vec<(f64, f64)>)>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    let n = data.len();
+    let nodes: Vec<String> = (1..=n).map(|x| format!("Node:{}", x)).collect();
+    let seq: Vec<String> = (1..=n).map(|x| format!("Seq:{}", x)).collect();
+    writeln!(f, "Size\t{}\t{}", nodes.join("\t"), seq.join("\t")).expect("Not able to write");
+    for (m, levels) in data.iter() {
+        let mut nodes: Vec<String> = levels.iter().map(|x| x.0.to_string()).collect();
+        let mut seq: Vec<String> = levels.iter().map(|x| x.1.to_string()).collect();
+        nodes.resize(n, String::new());
+        seq.resize(n, String::new());
+        writeln!(f, "{}\t{}\t{}", m, nodes.join("\t"), seq.join("\t")).expect("Not able to write");
+    }
+}
+
+/// Write Heaps' law fits (summary)
+///
+/// Pangenome: open (alpha < 1) or closed (alpha > 1), only for pan curves
+pub fn write_heaps(data: &Vec<(String, String, PowerLaw)>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    writeln!(
+        f,
+        "Curve\tUnit\tKappa\tKappa CI (low)\tKappa CI (high)\tAlpha\tAlpha CI (low)\tAlpha CI (high)\tR2\tPoints\tPangenome"
+    )
+    .expect("Not able to write");
+    for (curve, unit, fit) in data.iter() {
+        let openness = if curve != "Pan" || fit.alpha.is_nan() {
+            "-"
+        } else if fit.alpha < 1.0 {
+            "open"
+        } else {
+            "closed"
+        };
+        writeln!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            curve,
+            unit,
+            fit.kappa,
+            fit.kappa_ci.0,
+            fit.kappa_ci.1,
+            fit.alpha,
+            fit.alpha_ci.0,
+            fit.alpha_ci.1,
+            fit.r2,
+            fit.points,
+            openness
+        )
+        .expect("Not able to write");
+    }
+}
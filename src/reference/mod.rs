@@ -0,0 +1,3 @@
+mod reference_calc;
+pub mod reference_main;
+mod writer;
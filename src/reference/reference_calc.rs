@@ -0,0 +1,81 @@
+use gfa_reader::{Pansn, Path};
+
+/// Get the reference paths
+///
+/// Reference is either a sample (Pan-SN) or a single path
+pub fn get_reference_paths<'a>(
+    wrapper: &'a Pansn<u32, (), ()>,
+    name: &str,
+) -> Vec<&'a Path<u32, (), ()>> {
+    for (sample, paths) in wrapper.get_path_genome().into_iter() {
+        if sample == name {
+            return paths;
+        }
+        if let Some(path) = paths.iter().find(|p| p.name == name) {
+            return vec![*path];
+        }
+    }
+    panic!("Error: Reference {} not found in graph file", name)
+}
+
+/// Create a vector which contains 1 if the node is in one of the paths
+pub fn node_presence(paths: &Vec<&Path<u32, (), ()>>, length: usize) -> Vec<bool> {
+    let mut result = vec![false; length];
+    for path in paths.iter() {
+        for node in path.nodes.iter() {
+            result[*node as usize] = true;
+        }
+    }
+    result
+}
+
+/// Compare a sample to the reference (on node level)
+///
+/// Return [shared, insertion, deletion] as (number of nodes, sequence)
+pub fn reference_coverage(
+    reference: &[bool],
+    sample: &[bool],
+    node_size: &Vec<u32>,
+) -> [(usize, usize); 3] {
+    let mut result = [(0, 0); 3];
+    for ((r, s), size) in reference.iter().zip(sample.iter()).zip(node_size.iter()) {
+        let index = match (r, s) {
+            (true, true) => 0,
+            (false, true) => 1,
+            (true, false) => 2,
+            (false, false) => continue,
+        };
+        result[index].0 += 1;
+        result[index].1 += *size as usize;
+    }
+    result
+}
+
+/// Intervals of the reference paths which are not covered by the sample
+///
+/// Output is a vector of [reference path, start, end]
+pub fn missing_intervals(
+    reference: &Vec<&Path<u32, (), ()>>,
+    sample: &[bool],
+    node_size: &Vec<u32>,
+) -> Vec<(String, u64, u64)> {
+    let mut result = Vec::new();
+    for path in reference.iter() {
+        let mut pos: u64 = 0;
+        let mut start: Option<u64> = None;
+        for node in path.nodes.iter() {
+            if !sample[*node as usize] {
+                if start.is_none() {
+                    start = Some(pos);
+                }
+            } else if let Some(s) = start.take() {
+                result.push((path.name.clone(), s, pos));
+            }
+            pos += node_size[*node as usize] as u64;
+        }
+        if let Some(s) = start {
+            result.push((path.name.clone(), s, pos));
+        }
+    }
+    result
+}
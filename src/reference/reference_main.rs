@@ -0,0 +1,62 @@
+use crate::helpers::helper::calc_node_len;
+use crate::reference::reference_calc::{
+    get_reference_paths, missing_intervals, node_presence, reference_coverage,
+};
+use crate::reference::writer::{write_missing, write_summary};
+use clap::ArgMatches;
+use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
+use log::info;
+
+/// Main function for reference subcommand
+///
+/// Compare each sample to the reference: shared, inserted (non-reference) and deleted (missing reference) sequence
+pub fn reference_main(matches: &ArgMatches) {
+    info!("Running 'gretl reference'");
+    let mut sep = " ";
+    if matches.is_present("Pan-SN") {
+        sep = matches.value_of("Pan-SN").unwrap();
+    }
+
+    if check_numeric_gfafile(matches.value_of("gfa").unwrap()) {
+        let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
+        graph.walk_to_path(sep);
+        if graph.paths.is_empty() {
+            panic!("Error: No path found in graph file")
+        }
+        let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
+        let output = matches.value_of("output").unwrap();
+        let reference_name = matches.value_of("reference").unwrap();
+
+        info!("Gfa file: {}", matches.value_of("gfa").unwrap());
+        info!("Output file: {}", output);
+        info!("Reference: {}", reference_name);
+
+        let node_size = calc_node_len(&graph);
+        let reference = get_reference_paths(&wrapper, reference_name);
+        let reference_nodes = node_presence(&reference, node_size.len());
+
+        let mut summary = Vec::new();
+        let mut missing = Vec::new();
+        for (name, paths) in wrapper.get_path_genome().iter() {
+            // Skip the sample of the reference
+            if name == reference_name || paths.iter().any(|p| p.name == reference_name) {
+                continue;
+            }
+            let sample_nodes = node_presence(paths, node_size.len());
+            summary.push((
+                name.clone(),
+                reference_coverage(&reference_nodes, &sample_nodes, &node_size),
+            ));
+            missing.push((
+                name.clone(),
+                missing_intervals(&reference, &sample_nodes, &node_size),
+            ));
+        }
+
+        info!("Writing to file");
+        write_summary(&summary, output);
+        write_missing(&missing, &(output.to_string() + ".missing.bed"));
+    } else {
+        panic!("Error: GFA file is not numeric");
+    }
+}
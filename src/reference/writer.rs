@@ -0,0 +1,41 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Write the summary table (one sample per line)
+pub fn write_summary(data: &Vec<(String, [(usize, usize); 3])>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    writeln!(
+        f,
+        "Sample\tShared[bp]\tShared[fraction]\tInsertion[bp]\tDeletion[bp]\tShared#Node\tInsertion#Node\tDeletion#Node"
+    )
+    .expect("Not able to write");
+    for (name, [shared, insertion, deletion]) in data.iter() {
+        writeln!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            name,
+            shared.1,
+            shared.1 as f64 / (shared.1 + deletion.1) as f64,
+            insertion.1,
+            deletion.1,
+            shared.0,
+            insertion.0,
+            deletion.0
+        )
+        .expect("Not able to write");
+    }
+}
+
+/// Write the missing reference intervals (BED)
+///
+/// Columns: reference path, start, end, sample
+pub fn write_missing(data: &Vec<(String, Vec<(String, u64, u64)>)>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    for (sample, intervals) in data.iter() {
+        for (path, start, end) in intervals.iter() {
+            writeln!(f, "{}\t{}\t{}\t{}", path, start, end, sample).expect("Not able to write");
+        }
+    }
+}
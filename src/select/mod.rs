@@ -0,0 +1,3 @@
+pub mod select_calc;
+pub mod select_main;
+mod writer;
@@ -0,0 +1,64 @@
+/// Greedy selection of samples
+///
+/// Start with the required samples (in the given order), then always pick the sample which adds the most uncovered weight.
+/// Stop when the covered weight reaches 'target' or no sample adds anything.
+/// Ties are resolved by sample order.
+/// Output is a vector of [sample (index), required, new (nodes, weight), covered (nodes, weight)]
+pub fn greedy_selection(
+    samples: &Vec<Vec<u32>>,
+    weight: &Vec<u64>,
+    required: &[usize],
+    target: u64,
+) -> Vec<(usize, bool, (usize, u64), (usize, u64))> {
+    let mut covered = vec![false; weight.len()];
+    let mut picked = vec![false; samples.len()];
+    let mut total = (0, 0);
+    let mut result = Vec::new();
+
+    let mut add = |sample: usize, req: bool, covered: &mut Vec<bool>, result: &mut Vec<_>| {
+        let mut new = (0, 0);
+        for n in samples[sample].iter() {
+            if !covered[*n as usize] {
+                covered[*n as usize] = true;
+                new.0 += 1;
+                new.1 += weight[*n as usize];
+            }
+        }
+        total = (total.0 + new.0, total.1 + new.1);
+        result.push((sample, req, new, total));
+        total.1
+    };
+
+    let mut current = 0;
+    for sample in required.iter() {
+        if !picked[*sample] {
+            picked[*sample] = true;
+            current = add(*sample, true, &mut covered, &mut result);
+        }
+    }
+
+    while current < target {
+        let mut best: Option<(usize, u64)> = None;
+        for (i, nodes) in samples.iter().enumerate() {
+            if picked[i] {
+                continue;
+            }
+            let gain: u64 = nodes
+                .iter()
+                .filter(|n| !covered[**n as usize])
+                .map(|n| weight[*n as usize])
+                .sum();
+            if gain > 0 && best.map_or(true, |b| gain > b.1) {
+                best = Some((i, gain));
+            }
+        }
+        match best {
+            Some((i, _)) => {
+                picked[i] = true;
+                current = add(i, false, &mut covered, &mut result);
+            }
+            None => break,
+        }
+    }
+    result
+}
@@ -0,0 +1,102 @@
+use crate::helpers::helper::{calc_depth, calc_node_len};
+use crate::select::select_calc::greedy_selection;
+use crate::select::writer::write_selection;
+use clap::ArgMatches;
+use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
+use log::info;
+
+/// Main function for select subcommand
+///
+/// Greedy minimal set of samples which covers a fraction of the graph sequence
+pub fn select_main(matches: &ArgMatches) {
+    info!("Running 'gretl select'");
+    let mut sep = " ";
+    if matches.is_present("Pan-SN") {
+        sep = matches.value_of("Pan-SN").unwrap();
+    }
+
+    if check_numeric_gfafile(matches.value_of("gfa").unwrap()) {
+        let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
+        graph.walk_to_path(sep);
+        if graph.paths.is_empty() {
+            panic!("Error: No path found in graph file")
+        }
+        let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
+        let output = matches.value_of("output").unwrap();
+
+        // Target coverage in percent
+        let mut coverage: f64 = 100.0;
+        if matches.is_present("coverage") {
+            coverage = matches
+                .value_of("coverage")
+                .unwrap()
+                .parse()
+                .expect("Error: Coverage is not a number");
+        }
+        if !(0.0..=100.0).contains(&coverage) {
+            panic!("Error: Coverage must be between 0 and 100")
+        }
+
+        let paths = wrapper.get_path_genome();
+        let names: Vec<&String> = paths.iter().map(|x| &x.0).collect();
+
+        // Required samples (comma separated)
+        let mut required = Vec::new();
+        if let Some(samples) = matches.value_of("required") {
+            for sample in samples.split(',') {
+                required.push(
+                    names.iter().position(|x| *x == sample).unwrap_or_else(|| {
+                        panic!("Error: Sample {} not found in graph file", sample)
+                    }),
+                );
+            }
+        }
+
+        info!("Gfa file: {}", matches.value_of("gfa").unwrap());
+        info!("Output file: {}", output);
+        info!("Coverage: {}%", coverage);
+        info!("Required samples: {}", required.len());
+
+        // Weight of each node: sequence [bp] (optional: times depth)
+        let mut weight: Vec<u64> = calc_node_len(&graph).iter().map(|x| *x as u64).collect();
+        let mut unit = "[bp]";
+        if matches.is_present("depth") {
+            unit = "[bp x depth]";
+            let depth = calc_depth(&paths, &graph);
+            for (w, d) in weight.iter_mut().zip(depth.iter()) {
+                *w *= *d as u64;
+            }
+        }
+        let total: u64 = weight.iter().sum();
+        let target = (total as f64 * coverage / 100.0).ceil() as u64;
+
+        // Distinct nodes of each sample
+        let samples: Vec<Vec<u32>> = paths
+            .iter()
+            .map(|p| {
+                let mut nodes: Vec<u32> =
+                    p.1.iter().flat_map(|x| x.nodes.iter()).cloned().collect();
+                nodes.sort();
+                nodes.dedup();
+                nodes
+            })
+            .collect();
+
+        info!("Greedy selection");
+        let selection = greedy_selection(&samples, &weight, &required, target);
+        if let Some(last) = selection.last() {
+            if last.3 .1 < target {
+                info!("Coverage of {}% can not be reached", coverage);
+            }
+        }
+
+        info!("Writing to file");
+        let data: Vec<(String, bool, (usize, u64), (usize, u64))> = selection
+            .iter()
+            .map(|(i, req, new, covered)| (names[*i].to_string(), *req, *new, *covered))
+            .collect();
+        write_selection(&data, total, unit, output);
+    } else {
+        panic!("Error: GFA file is not numeric");
+    }
+}
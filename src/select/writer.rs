@@ -0,0 +1,37 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+/// Write the selected samples (in selection order)
+///
+/// Unit of the weight: [bp] or [bp x depth]
+/// Coverage: covered weight / total weight
+pub fn write_selection(
+    data: &[(String, bool, (usize, u64), (usize, u64))],
+    total: u64,
+    unit: &str,
+    filename: &str,
+) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    writeln!(
+        f,
+        "Step\tSample\tRequired\tNew #Node\tNew {}\tCovered #Node\tCovered {}\tCoverage",
+        unit, unit
+    )
+    .expect("Not able to write");
+    for (i, (sample, required, new, covered)) in data.iter().enumerate() {
+        writeln!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            i + 1,
+            sample,
+            if *required { "yes" } else { "no" },
+            new.0,
+            new.1,
+            covered.0,
+            covered.1,
+            covered.1 as f64 / total as f64
+        )
+        .expect("Not able to write");
+    }
+}
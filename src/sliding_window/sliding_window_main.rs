@@ -1,22 +1,28 @@
-use crate::sliding_window::window::sliding_window_wrapper;
-use crate::sliding_window::writer::write_window;
+use crate::helpers::helper::calc_node_len;
+use crate::helpers::region::restrict_graph;
+use crate::sliding_window::window::{metric_values, path2bedgraph, sliding_window_wrapper};
+use crate::sliding_window::writer::{write_bedgraph, write_window, write_window_bed};
 use clap::ArgMatches;
 use gfa_reader::{check_numeric_gfafile, Gfa, Pansn};
 use log::info;
 
 /// Main function for node id to integer function
-pub fn window_main(matches: &ArgMatches) {
+pub fn window_main(matches: &ArgMatches, regions: &[(String, u64, u64)], output: &str) {
     info!("Running 'gretl window'");
 
     if check_numeric_gfafile(matches.value_of("gfa").unwrap()) {
         let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
         graph.walk_to_path("#");
+        // Start of each path (fragment) on the original path
+        let mut offsets = vec![0; graph.paths.len()];
+        if !regions.is_empty() {
+            offsets = restrict_graph(&mut graph, regions);
+        }
         if graph.paths.is_empty() {
             panic!("Error: No path found in graph file")
         }
         let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, " ");
 
-        let output = matches.value_of("output").unwrap();
         let mut size: u32 = 100000;
         if matches.is_present("window-size") {
             size = matches.value_of("window-size").unwrap().parse().unwrap();
@@ -27,39 +33,218 @@ pub fn window_main(matches: &ArgMatches) {
             step = matches.value_of("moving-size").unwrap().parse().unwrap();
         }
 
-        let mut node = false;
+        let mut mode = WindowMode::Bp;
         if matches.is_present("node") {
-            node = true;
+            mode = WindowMode::Node;
+        }
+        if matches.is_present("mode") {
+            mode = match matches.value_of("mode").unwrap() {
+                "bp" => WindowMode::Bp,
+                "node" => WindowMode::Node,
+                "distinct" => WindowMode::Distinct,
+                "graph-bp" => WindowMode::GraphBp,
+                "bubble" => WindowMode::Bubble,
+                x => panic!("Error: Unknown window mode {}", x),
+            };
         }
 
-        // similarity
-        let mut metric = Metric::Similarity;
+        // Metrics (comma separated)
+        let mut metrics = vec![Metric::Similarity];
         if matches.is_present("metric") {
-            match matches.value_of("metric").unwrap() {
-                "similarity" => metric = Metric::Nodesizem,
-                "nodesize" => metric = Metric::Similarity,
-                "depth" => metric = Metric::Depth,
-                _ => metric = Metric::Similarity,
+            metrics = matches
+                .value_of("metric")
+                .unwrap()
+                .split(',')
+                .map(parse_metric)
+                .collect();
+        }
+
+        // Fraction above this threshold
+        let mut threshold: u32 = 1;
+        if matches.is_present("threshold") {
+            threshold = matches.value_of("threshold").unwrap().parse().unwrap();
+        }
+
+        // Aggregations (comma separated)
+        let mut aggregations = vec![Aggregation::Mean];
+        if matches.is_present("aggregation") {
+            aggregations = matches
+                .value_of("aggregation")
+                .unwrap()
+                .split(',')
+                .map(|x| parse_aggregation(x, threshold))
+                .collect();
+        }
+
+        // One column for each metric x aggregation
+        let mut columns = Vec::new();
+        for metric in metrics.iter() {
+            for aggregation in aggregations.iter() {
+                columns.push((metric.clone(), aggregation.clone()));
             }
         }
+        let names: Vec<String> = columns
+            .iter()
+            .map(|(m, a)| format!("{}:{}", m.name(), a.name()))
+            .collect();
+
         info!("Gfa file: {}", matches.value_of("gfa").unwrap());
         info!("Output file: {}", output);
         info!("Window size: {}", size);
         info!("Moving size: {}", step);
-        info!("Node: {}", node);
-        info!("Metric: {:?}", "Similarity");
+        info!("Mode: {:?}", mode);
+        info!("Columns: {}", names.join(", "));
+
+        if matches.is_present("bedgraph") {
+            info!("Projecting metric on paths (bedGraph)");
+            if metrics.len() > 1 {
+                panic!("Error: bedGraph output only supports a single metric")
+            }
+            let node_len = calc_node_len(&graph);
+            let core = metric_values(&metrics[0], &wrapper.get_path_genome(), &graph);
+            let mut data = Vec::new();
+            for (path, offset) in graph.paths.iter().zip(offsets.iter()) {
+                if let Some(name) = matches.value_of("path") {
+                    if path.name != name {
+                        continue;
+                    }
+                }
+                data.push((
+                    path.name.clone(),
+                    path2bedgraph(path, &node_len, &core, *offset),
+                ));
+            }
+            if data.is_empty() {
+                panic!(
+                    "Error: Path {} not found in graph file",
+                    matches.value_of("path").unwrap()
+                )
+            }
+            info!("Writing to file");
+            write_bedgraph(&data, output);
+            return;
+        }
 
         info!("Sliding window analysis");
-        let f = sliding_window_wrapper(&graph, &wrapper, size, step, metric, node);
+        let f = sliding_window_wrapper(&graph, &wrapper, size, step, &columns, &mode, &offsets);
         info!("Writing to file");
-        write_window(f, output);
+        if matches.is_present("bed") {
+            write_window_bed(&f, &names, output);
+        } else if columns.len() == 1 {
+            write_window(&f, 0, output);
+        } else {
+            // One table for each column
+            for (i, name) in names.iter().enumerate() {
+                write_window(
+                    &f,
+                    i,
+                    &format!(
+                        "{}.{}.txt",
+                        output,
+                        name.replace(':', ".").replace('>', "_")
+                    ),
+                );
+            }
+        }
     } else {
         panic!("Error: GFA file is not numeric");
     }
 }
 
+/// How windows are defined
+///
+/// Bp and Node are sliding windows (window and step size), all others are adaptive (non-overlapping)
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowMode {
+    Bp,
+    Node,
+    Distinct,
+    GraphBp,
+    Bubble,
+}
+
+impl WindowMode {
+    /// Windows are measured in nodes (not bp)
+    pub fn node_units(&self) -> bool {
+        matches!(self, WindowMode::Node | WindowMode::Distinct)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Metric {
     Similarity,
-    Nodesizem,
+    Nodesize,
     Depth,
+    Degree,
+    DegreeIn,
+    DegreeOut,
+    Forward,
+    Reverse,
+    Private,
+}
+
+impl Metric {
+    pub fn name(&self) -> &str {
+        match self {
+            Metric::Similarity => "similarity",
+            Metric::Nodesize => "nodesize",
+            Metric::Depth => "depth",
+            Metric::Degree => "nd",
+            Metric::DegreeIn => "nd_in",
+            Metric::DegreeOut => "nd_out",
+            Metric::Forward => "forward",
+            Metric::Reverse => "reverse",
+            Metric::Private => "private",
+        }
+    }
+}
+
+/// Summary of all values in a window
+///
+/// Fraction: fraction of values (bp or nodes) above the threshold
+#[derive(Debug, Clone, PartialEq)]
+pub enum Aggregation {
+    Mean,
+    Median,
+    Min,
+    Max,
+    Fraction(u32),
+}
+
+impl Aggregation {
+    pub fn name(&self) -> String {
+        match self {
+            Aggregation::Mean => "mean".to_string(),
+            Aggregation::Median => "median".to_string(),
+            Aggregation::Min => "min".to_string(),
+            Aggregation::Max => "max".to_string(),
+            Aggregation::Fraction(threshold) => format!("fraction>{}", threshold),
+        }
+    }
+}
+
+pub fn parse_metric(name: &str) -> Metric {
+    match name {
+        "similarity" => Metric::Similarity,
+        "nodesize" => Metric::Nodesize,
+        "depth" => Metric::Depth,
+        "nd" => Metric::Degree,
+        "nd_in" => Metric::DegreeIn,
+        "nd_out" => Metric::DegreeOut,
+        "forward" => Metric::Forward,
+        "reverse" => Metric::Reverse,
+        "private" => Metric::Private,
+        _ => panic!("Error: Unknown metric {}", name),
+    }
+}
+
+pub fn parse_aggregation(name: &str, threshold: u32) -> Aggregation {
+    match name {
+        "mean" => Aggregation::Mean,
+        "median" => Aggregation::Median,
+        "min" => Aggregation::Min,
+        "max" => Aggregation::Max,
+        "fraction" => Aggregation::Fraction(threshold),
+        _ => panic!("Error: Unknown aggregation {}", name),
+    }
 }
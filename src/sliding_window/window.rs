@@ -1,100 +1,359 @@
-use crate::helpers::helper::{calc_depth, calc_node_len, calc_similarity};
-use crate::sliding_window::sliding_window_main::Metric;
+use crate::helpers::helper::{
+    calc_depth, calc_node_degree, calc_node_len, calc_orientation, calc_similarity,
+};
+use crate::sliding_window::sliding_window_main::{Aggregation, Metric, WindowMode};
 use gfa_reader::{Gfa, Pansn, Path};
-use std::fmt::Debug;
+use std::cmp::{max, min};
+use std::collections::HashSet;
+
+/// A single window on a path
+///
+/// Coordinates (start, end) are in bp on the path, end exclusive (BED)
+/// Values: one value for each metric x aggregation (same order as the columns)
+#[derive(Debug, Clone)]
+pub struct Window {
+    pub start: u64,
+    pub end: u64,
+    pub nodes: u64,
+    pub bp: u64,
+    pub values: Vec<f64>,
+}
 
 /// Wrapper for sliding window
 ///
-/// TODO
-/// - add different metrics
+/// Computes all metrics and aggregations in a single pass
+/// Offsets: start of each path on the original path (region fragments), shifts the window coordinates
 pub fn sliding_window_wrapper(
     graph: &Gfa<u32, (), ()>,
     wrapper: &Pansn<u32, (), ()>,
     binsize: u32,
     stepsize: u32,
-    metric: Metric,
-    node: bool,
-) -> Vec<(String, Vec<f64>)> {
+    columns: &Vec<(Metric, Aggregation)>,
+    mode: &WindowMode,
+    offsets: &[u64],
+) -> Vec<(String, Vec<Window>)> {
     let paths = wrapper.get_path_genome();
+    let node_len = calc_node_len(graph);
+    let node = mode.node_units();
 
-    let mut result = Vec::new();
-    let mut core = calc_similarity(&paths, graph);
-    match metric {
-        Metric::Nodesizem => core = calc_node_len(graph),
-        Metric::Depth => core = calc_depth(&paths, graph),
-        Metric::Similarity => {}
+    // Bubble boundaries: nodes traversed by all samples
+    let anchors: Vec<bool> = match mode {
+        WindowMode::Bubble => calc_similarity(&paths, graph)
+            .iter()
+            .map(|x| *x as usize == paths.len())
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    // Each metric is only computed once
+    let mut metrics: Vec<&Metric> = Vec::new();
+    for (metric, _) in columns.iter() {
+        if !metrics.contains(&metric) {
+            metrics.push(metric);
+        }
     }
+    let cores: Vec<Vec<u32>> = metrics
+        .iter()
+        .map(|metric| metric_values(metric, &paths, graph))
+        .collect();
 
-    let node_len = calc_node_len(graph);
-    for path in graph.paths.iter() {
-        let vector = path2metric_vector(path, &node_len, &core, &node);
-        let sww = sliding_window(vector, binsize, stepsize);
-        result.push((path.name.clone(), sww));
+    let mut result = Vec::new();
+    for (path, offset) in graph.paths.iter().zip(offsets.iter()) {
+        let runs: Vec<Runs> = cores
+            .iter()
+            .map(|core| path2runs(path, &node_len, core, node))
+            .collect();
+        let coordinates = match mode {
+            WindowMode::Bp | WindowMode::Node => {
+                window_coordinates(path, &node_len, binsize, stepsize, node)
+            }
+            _ => adaptive_coordinates(path, &node_len, binsize, mode, &anchors),
+        };
+        let windows = coordinates
+            .iter()
+            .map(|(start, end, nodes, (s, e))| Window {
+                start: start + offset,
+                end: end + offset,
+                nodes: *nodes,
+                bp: end - start,
+                values: columns
+                    .iter()
+                    .map(|(metric, aggregation)| {
+                        let index = metrics.iter().position(|m| *m == metric).unwrap();
+                        runs[index].aggregate(*s, *e, aggregation)
+                    })
+                    .collect(),
+            })
+            .collect();
+        result.push((path.name.clone(), windows));
     }
     result
 }
 
-/// Create the vector for sliding window
-pub fn path2metric_vector(
+/// Coordinates of each window
+///
+/// Output is a vector of [start (bp), end (bp), number of nodes, (start, end) in window units (bp or nodes)]
+/// Windows start every step until the end of the path, the first window which exceeds the path is truncated to the end of the path (and is the last one).
+pub fn window_coordinates(
     path: &Path<u32, (), ()>,
     node_len: &Vec<u32>,
-    core: &Vec<u32>,
-    node: &bool,
-) -> Vec<u32> {
-    let mut metric_vector = Vec::new();
-    if *node {
-        for n in path.nodes.iter() {
-            let level = core[*n as usize];
-            metric_vector.push(level);
-        }
-    } else {
-        for n in path.nodes.iter() {
-            let size = node_len[*n as usize];
-            let level = core[*n as usize];
-            for _x in 0..size {
-                metric_vector.push(level);
-            }
-        }
+    binsize: u32,
+    step: u32,
+    node: bool,
+) -> Vec<(u64, u64, u64, (u64, u64))> {
+    // Start position of each node in the path (+ end of the path)
+    let mut offsets: Vec<u64> = Vec::with_capacity(path.nodes.len() + 1);
+    let mut pos: u64 = 0;
+    for n in path.nodes.iter() {
+        offsets.push(pos);
+        pos += node_len[*n as usize] as u64;
     }
-    metric_vector
-}
+    offsets.push(pos);
 
-/// Sliding window
-pub fn sliding_window(input: Vec<u32>, binsize_input: u32, step: u32) -> Vec<f64> {
-    let binsize = binsize_input as usize;
-    let mut start = 0;
-    let maxsize = input.len();
+    let maxsize = if node { path.nodes.len() as u64 } else { pos };
     let mut result = Vec::new();
+    let mut start: u64 = 0;
     while start < maxsize {
-        let a = &start + &binsize;
-        if a > maxsize {
-            let f: f64 = calculate_average(&input[start..maxsize]).unwrap();
-            result.push(f);
+        let end = min(start + binsize as u64, maxsize);
+        let range = (start, end);
+        if node {
+            result.push((
+                offsets[start as usize],
+                offsets[end as usize],
+                end - start,
+                range,
+            ));
+        } else {
+            // Nodes which overlap the window
+            let first = offsets.partition_point(|x| *x <= start) - 1;
+            let last = offsets.partition_point(|x| *x < end) - 1;
+            result.push((start, end, (last - first + 1) as u64, range));
+        }
+        if start + binsize as u64 > maxsize {
             break;
         }
-        let f: f64 = calculate_average(&input[start..a]).unwrap();
+        start += step as u64;
+    }
+    result
+}
 
-        result.push(f);
-        start += step as usize;
+/// Coordinates of adaptive windows
+///
+/// Windows do not overlap and always start and end at node boundaries. A window is closed when
+/// - Distinct: it contains size distinct nodes
+/// - GraphBp: the distinct nodes in the window sum up to size bp
+/// - Bubble: it is at least size bp long and the last node is a bubble boundary (anchor)
+///
+/// Output has the same format as window_coordinates (window units: nodes for Distinct, otherwise bp)
+pub fn adaptive_coordinates(
+    path: &Path<u32, (), ()>,
+    node_len: &Vec<u32>,
+    size: u32,
+    mode: &WindowMode,
+    anchors: &Vec<bool>,
+) -> Vec<(u64, u64, u64, (u64, u64))> {
+    let mut result = Vec::new();
+    let mut seen: HashSet<u32> = HashSet::new();
+    let mut graph_bp: u64 = 0;
+    let mut first = 0;
+    let mut start: u64 = 0;
+    let mut pos: u64 = 0;
+    for (i, n) in path.nodes.iter().enumerate() {
+        pos += node_len[*n as usize] as u64;
+        if seen.insert(*n) {
+            graph_bp += node_len[*n as usize] as u64;
+        }
+        let close = match mode {
+            WindowMode::Distinct => seen.len() as u64 >= size as u64,
+            WindowMode::GraphBp => graph_bp >= size as u64,
+            WindowMode::Bubble => pos - start >= size as u64 && anchors[*n as usize],
+            _ => false,
+        };
+        if close || i == path.nodes.len() - 1 {
+            let range = if mode.node_units() {
+                (first as u64, (i + 1) as u64)
+            } else {
+                (start, pos)
+            };
+            result.push((start, pos, (i + 1 - first) as u64, range));
+            seen.clear();
+            graph_bp = 0;
+            first = i + 1;
+            start = pos;
+        }
     }
     result
 }
 
-fn calculate_average<T>(v: &[T]) -> Option<f64>
-where
-    T: Into<f64> + Copy + Debug,
-{
-    if v.is_empty() {
-        return None;
+/// Metric of a path, compressed in runs of consecutive nodes with the same value
+///
+/// Length of each run is in window units (bp or nodes), memory is O(nodes) instead of O(bp)
+pub struct Runs {
+    // Start of each run (+ end of the path)
+    pub offsets: Vec<u64>,
+    pub values: Vec<u32>,
+    // Prefix sum of value * length
+    pub prefix: Vec<f64>,
+}
+
+/// Convert a path into runs of a metric
+///
+/// Node mode: each node has length 1, otherwise node length [bp]
+pub fn path2runs(
+    path: &Path<u32, (), ()>,
+    node_len: &Vec<u32>,
+    core: &Vec<u32>,
+    node: bool,
+) -> Runs {
+    let mut offsets: Vec<u64> = vec![0];
+    let mut values: Vec<u32> = Vec::new();
+    let mut pos: u64 = 0;
+    for n in path.nodes.iter() {
+        let size = if node {
+            1
+        } else {
+            node_len[*n as usize] as u64
+        };
+        if size == 0 {
+            continue;
+        }
+        let level = core[*n as usize];
+        pos += size;
+        if values.last() == Some(&level) {
+            *offsets.last_mut().unwrap() = pos;
+        } else {
+            values.push(level);
+            offsets.push(pos);
+        }
+    }
+
+    let mut prefix: Vec<f64> = Vec::with_capacity(offsets.len());
+    prefix.push(0.0);
+    for (i, value) in values.iter().enumerate() {
+        let length = offsets[i + 1] - offsets[i];
+        prefix.push(prefix[i] + *value as f64 * length as f64);
     }
+    Runs {
+        offsets,
+        values,
+        prefix,
+    }
+}
 
-    let mut mean: f64 = f64::from(0);
-    let mut count: f64 = 0.0;
+impl Runs {
+    /// Runs which overlap the window [start, end) and the size of the overlap
+    fn overlap(&self, start: u64, end: u64) -> Vec<(u32, u64)> {
+        let first = self.offsets.partition_point(|x| *x <= start) - 1;
+        let last = self.offsets.partition_point(|x| *x < end) - 1;
+        (first..=last)
+            .map(|i| {
+                let size = min(end, self.offsets[i + 1]) - max(start, self.offsets[i]);
+                (self.values[i], size)
+            })
+            .collect()
+    }
 
-    for &value in v {
-        mean += (value.into() - mean) / (count + 1.0);
-        count += 1.0;
+    /// Summarize the values of the window [start, end)
+    ///
+    /// Every unit (bp or node) in the window is weighted equally
+    pub fn aggregate(&self, start: u64, end: u64, aggregation: &Aggregation) -> f64 {
+        if start >= end || end > *self.offsets.last().unwrap() {
+            return f64::NAN;
+        }
+        let total = (end - start) as f64;
+        match aggregation {
+            Aggregation::Mean => {
+                // Prefix sums, remove the parts of the first and last run outside the window
+                let first = self.offsets.partition_point(|x| *x <= start) - 1;
+                let last = self.offsets.partition_point(|x| *x < end) - 1;
+                let sum = self.prefix[last + 1]
+                    - self.prefix[first]
+                    - self.values[first] as f64 * (start - self.offsets[first]) as f64
+                    - self.values[last] as f64 * (self.offsets[last + 1] - end) as f64;
+                sum / total
+            }
+            Aggregation::Median => {
+                let mut overlap = self.overlap(start, end);
+                overlap.sort();
+                let size = end - start;
+                if size % 2 == 0 {
+                    (weighted_rank(&overlap, size / 2 - 1) + weighted_rank(&overlap, size / 2))
+                        as f64
+                        / 2.0
+                } else {
+                    weighted_rank(&overlap, size / 2) as f64
+                }
+            }
+            Aggregation::Min => self.overlap(start, end).iter().map(|x| x.0).min().unwrap() as f64,
+            Aggregation::Max => self.overlap(start, end).iter().map(|x| x.0).max().unwrap() as f64,
+            Aggregation::Fraction(threshold) => {
+                let above: u64 = self
+                    .overlap(start, end)
+                    .iter()
+                    .filter(|x| x.0 > *threshold)
+                    .map(|x| x.1)
+                    .sum();
+                above as f64 / total
+            }
+        }
     }
+}
 
-    Some(mean)
+/// Value at rank k (0-based) of sorted (value, count) pairs
+fn weighted_rank(sorted: &[(u32, u64)], k: u64) -> u32 {
+    let mut seen = 0;
+    for (value, count) in sorted.iter() {
+        seen += count;
+        if k < seen {
+            return *value;
+        }
+    }
+    sorted.last().unwrap().0
+}
+
+/// Value of the metric for each node
+pub fn metric_values(
+    metric: &Metric,
+    paths: &Vec<(String, Vec<&Path<u32, (), ()>>)>,
+    graph: &Gfa<u32, (), ()>,
+) -> Vec<u32> {
+    match metric {
+        Metric::Similarity => calc_similarity(paths, graph),
+        Metric::Nodesize => calc_node_len(graph),
+        Metric::Depth => calc_depth(paths, graph),
+        Metric::DegreeIn => calc_node_degree(graph).0,
+        Metric::DegreeOut => calc_node_degree(graph).1,
+        Metric::Degree => calc_node_degree(graph).2,
+        Metric::Forward => calc_orientation(paths, graph).0,
+        Metric::Reverse => calc_orientation(paths, graph).1,
+        Metric::Private => calc_similarity(paths, graph)
+            .iter()
+            .map(|x| (*x == 1) as u32)
+            .collect(),
+    }
+}
+
+/// Project the metric on a path (bedGraph)
+///
+/// Consecutive nodes with the same value are merged into one record.
+/// Offset: start of the path on the original path (region fragments)
+/// Output is a vector of [start, end, value]
+pub fn path2bedgraph(
+    path: &Path<u32, (), ()>,
+    node_len: &Vec<u32>,
+    core: &Vec<u32>,
+    offset: u64,
+) -> Vec<(u64, u64, u32)> {
+    let mut result: Vec<(u64, u64, u32)> = Vec::new();
+    let mut pos: u64 = offset;
+    for n in path.nodes.iter() {
+        let size = node_len[*n as usize] as u64;
+        let level = core[*n as usize];
+        match result.last_mut() {
+            Some(last) if last.2 == level => last.1 = pos + size,
+            _ => result.push((pos, pos + size, level)),
+        }
+        pos += size;
+    }
+    result
 }
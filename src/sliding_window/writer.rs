@@ -1,3 +1,4 @@
+use crate::sliding_window::window::Window;
 use std::fs::File;
 use std::io::{BufWriter, Write};
 
@@ -8,21 +9,59 @@ use std::io::{BufWriter, Write};
 /// - Each column is a bin
 ///
 /// Comment: All rows have the same length. If vector is smaller then max_size (longest vector in the data set), add NaN
-///
-pub fn write_window(data: Vec<(String, Vec<f64>)>, filename: &str) {
+/// Only the values of a single column (metric x aggregation) are reported
+pub fn write_window(data: &Vec<(String, Vec<Window>)>, column: usize, filename: &str) {
     let f = File::create(filename).expect("Unable to create file");
     let mut f = BufWriter::new(f);
     let maxsize: usize = data.iter().map(|n| n.1.len()).max().unwrap();
     for dat in data.iter() {
-        let mut vec_s: Vec<String> = dat.1.iter().map(|n| n.to_string()).collect();
+        let mut vec_s: Vec<String> = dat.1.iter().map(|n| n.values[column].to_string()).collect();
         filler(&mut vec_s, maxsize);
         writeln!(f, "{}\t{}", dat.0, vec_s.join("\t")).expect("Not able to write");
     }
 }
 
+/// Write output of window command in BED format (long)
+///
+/// One window per line: path, start, end, number of nodes, sequence [bp], one column per metric x aggregation
+pub fn write_window_bed(data: &Vec<(String, Vec<Window>)>, columns: &[String], filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    writeln!(f, "#path\tstart\tend\tnodes\tbp\t{}", columns.join("\t")).expect("Not able to write");
+    for (name, windows) in data.iter() {
+        for w in windows.iter() {
+            let values: Vec<String> = w.values.iter().map(|x| x.to_string()).collect();
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                name,
+                w.start,
+                w.end,
+                w.nodes,
+                w.bp,
+                values.join("\t")
+            )
+            .expect("Not able to write");
+        }
+    }
+}
+
 /// Fills string vector with new NaN (this can be modular in the future too)
 pub fn filler(data: &mut Vec<String>, maxsize: usize) {
     while data.len() < maxsize {
         data.push("NaN".to_string());
     }
 }
+
+/// Write the projected metric in bedGraph format
+///
+/// Columns: path, start, end, value
+pub fn write_bedgraph(data: &Vec<(String, Vec<(u64, u64, u32)>)>, filename: &str) {
+    let f = File::create(filename).expect("Unable to create file");
+    let mut f = BufWriter::new(f);
+    for (name, records) in data.iter() {
+        for (start, end, value) in records.iter() {
+            writeln!(f, "{}\t{}\t{}\t{}", name, start, end, value).expect("Not able to write");
+        }
+    }
+}
@@ -1,9 +1,17 @@
 use crate::helpers::helper::{average_median_std, mean, median};
-use crate::helpers::helper::{calc_depth, calc_node_degree, calc_node_len, calc_similarity};
+use crate::helpers::helper::{
+    calc_copy_number, calc_depth, calc_node_degree, calc_node_len, calc_orientation,
+    calc_similarity,
+};
 use crate::stats::hybrid_stats::path_stats_wrapper2;
-use gfa_reader::{Gfa, Pansn};
+use crate::stats::path_stats::{
+    calc_node_offset, jump_distribution, path_jump_sizes, path_jump_sizes_bp, path_jumps_bigger,
+    path_repeats, JUMP_BINS, JUMP_BINS_BP,
+};
+use gfa_reader::{Gfa, Pansn, Path};
 use log::info;
 use std::cmp::max;
+use std::collections::HashSet;
 
 /// Wrapper for graph statistics
 pub fn graph_stats_wrapper(
@@ -11,6 +19,8 @@ pub fn graph_stats_wrapper(
     wrapper: &Pansn<u32, (), ()>,
     bins: Vec<u32>,
     haplo: bool,
+    jump_threshold: Option<i32>,
+    reference: Option<&Path<u32, (), ()>>,
 ) -> Vec<(String, String)> {
     let mut result: Vec<(String, String)> = Vec::new();
 
@@ -111,6 +121,33 @@ pub fn graph_stats_wrapper(
         "Depth std (normalized)".to_string(),
         (a3 / number_samples as f64).to_string(),
     ));
+    // Nodes traversed in both orientations (by the same or different samples)
+    let (forward, reverse) = calc_orientation(&paths, graph);
+    let (bi_nodes, bi_seq) = bi_oriented(&forward, &reverse, &node_size);
+    result.push(("Bi-oriented nodes".to_string(), bi_nodes.to_string()));
+    result.push(("Bi-oriented nodes [bp]".to_string(), bi_seq.to_string()));
+
+    // Collapsed repeats: nodes traversed multiple times by at least one path
+    let mut max_copies = vec![0; node_size.len()];
+    for path in paths.iter() {
+        for (m, c) in max_copies
+            .iter_mut()
+            .zip(calc_copy_number(&path.1, graph).iter())
+        {
+            *m = max(*m, *c);
+        }
+    }
+    let (repeat_nodes, repeat_seq, max_copy) = path_repeats(&max_copies, &node_size);
+    result.push((
+        "Collapsed repeat nodes".to_string(),
+        repeat_nodes.to_string(),
+    ));
+    result.push((
+        "Collapsed repeat nodes [bp]".to_string(),
+        repeat_seq.to_string(),
+    ));
+    result.push(("Max copy number".to_string(), max_copy.to_string()));
+
     // Total length of paths
 
     // Node degree
@@ -157,8 +194,31 @@ pub fn graph_stats_wrapper(
         graph_density(graph).to_string(),
     ));
 
+    // Jumps (all paths)
+    let all_paths: Vec<&Path<u32, (), ()>> = graph.paths.iter().collect();
+    let jump_sizes = path_jump_sizes(&all_paths);
+    result.push((
+        "Jumps total".to_string(),
+        jump_sizes.iter().sum::<u64>().to_string(),
+    ));
+    result.push((
+        "Jumps bigger than X".to_string(),
+        path_jumps_bigger(&all_paths, jump_threshold).to_string(),
+    ));
+    for x in jump_distribution(&jump_sizes, "Jumps", &JUMP_BINS).iter() {
+        result.push((x.0.to_string(), x.1.to_string()));
+    }
+    let jump_sizes_bp = path_jump_sizes_bp(&all_paths, &calc_node_offset(&node_size), &node_size);
+    result.push((
+        "Jumps total [bp]".to_string(),
+        jump_sizes_bp.iter().sum::<u64>().to_string(),
+    ));
+    for x in jump_distribution(&jump_sizes_bp, "Jumps [bp]", &JUMP_BINS_BP).iter() {
+        result.push((x.0.to_string(), x.1.to_string()));
+    }
+
     info!("Calculating hybrid stats");
-    let hybrid_stats = path_stats_wrapper2(graph, wrapper, haplo);
+    let hybrid_stats = path_stats_wrapper2(graph, wrapper, haplo, jump_threshold, reference);
     for x in hybrid_stats.iter() {
         result.push((x.0.to_string(), x.1.to_string()));
     }
@@ -167,8 +227,15 @@ pub fn graph_stats_wrapper(
 }
 
 /// Number of paths
+///
+/// Fragments of a path (region) have the same name and are only counted once
 fn graph_path_number(graph: &Gfa<u32, (), ()>) -> usize {
-    graph.paths.len()
+    graph
+        .paths
+        .iter()
+        .map(|p| &p.name)
+        .collect::<HashSet<&String>>()
+        .len()
 }
 
 /// Number of nodes
@@ -303,3 +370,16 @@ pub fn size5pro(f: &mut Vec<u32>) -> (f64, f64) {
 
     (median(top5), mean(top5))
 }
+
+/// Number and sequence of nodes which are traversed in both orientations
+pub fn bi_oriented(forward: &[u32], reverse: &[u32], node_size: &[u32]) -> (usize, usize) {
+    let mut nodes = 0;
+    let mut seq = 0;
+    for ((f, r), size) in forward.iter().zip(reverse.iter()).zip(node_size.iter()) {
+        if *f > 0 && *r > 0 {
+            nodes += 1;
+            seq += *size as usize;
+        }
+    }
+    (nodes, seq)
+}
@@ -1,14 +1,16 @@
 use crate::helpers::helper::{mean, standard_deviation};
 use crate::stats::path_stats::path_stats_wrapper;
-use gfa_reader::{Gfa, Pansn};
+use gfa_reader::{Gfa, Pansn, Path};
 
 /// Wrapper for path statistics
 pub fn path_stats_wrapper2(
     graph: &Gfa<u32, (), ()>,
     gw: &Pansn<u32, (), ()>,
     haplo: bool,
+    jump_threshold: Option<i32>,
+    reference: Option<&Path<u32, (), ()>>,
 ) -> Vec<(String, f64)> {
-    let path_stats = path_stats_wrapper(graph, gw, haplo);
+    let path_stats = path_stats_wrapper(graph, gw, haplo, jump_threshold, reference);
 
     let mut tmp_res = Vec::new();
     let mut tmp_names = Vec::new();
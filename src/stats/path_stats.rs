@@ -1,7 +1,10 @@
 use crate::helpers::helper::{
-    average_median_std, calc_depth, calc_node_degree, calc_node_len, calc_similarity,
+    average_median_std, calc_copy_number, calc_depth, calc_node_degree, calc_node_len,
+    calc_similarity, percentile,
 };
+use crate::inversion::inversion_calc::{find_inversions, inversion_summary, reference_orientation};
 use gfa_reader::{Gfa, Pansn, Path};
+use std::cmp::{max, min};
 
 /// Wrapper for path statistics
 ///
@@ -10,6 +13,8 @@ pub fn path_stats_wrapper(
     graph: &Gfa<u32, (), ()>,
     wrapper: &Pansn<u32, (), ()>,
     haplo: bool,
+    jump_threshold: Option<i32>,
+    reference: Option<&Path<u32, (), ()>>,
 ) -> Vec<(String, Vec<(String, f64)>)> {
     // Total results
     let mut res = Vec::new();
@@ -30,6 +35,8 @@ pub fn path_stats_wrapper(
     // Calculate depth
     let depth = calc_depth(&paths, graph);
     let node_size = calc_node_len(graph);
+    let node_offset = calc_node_offset(&node_size);
+    let reference_orientation = reference.map(|r| reference_orientation(r, &node_size));
     let sum_graph: f64 = graph
         .segments
         .iter()
@@ -75,6 +82,12 @@ pub fn path_stats_wrapper(
             dir_nodes / edges_total_numb,
         ));
 
+        let (repeated_nodes, repeated_seq, max_copy) =
+            path_repeats(&calc_copy_number(&path.1, graph), &node_size);
+        result_temp.push(("Repeated nodes".to_string(), repeated_nodes as f64));
+        result_temp.push(("Repeated nodes [bp]".to_string(), repeated_seq as f64));
+        result_temp.push(("Max copy number".to_string(), max_copy as f64));
+
         let inverted = path_seq_inverted(&path.1, graph);
 
         result_temp.push(("Inverted nodes".to_string(), inverted.0 as f64));
@@ -89,6 +102,20 @@ pub fn path_stats_wrapper(
             inverted.1 as f64 / path_seq,
         ));
 
+        // Inversions relative to the reference path
+        if let Some(orientation) = reference_orientation.as_ref() {
+            let mut inversions = Vec::new();
+            for p in path.1.iter() {
+                if p.name != reference.unwrap().name {
+                    inversions.extend(find_inversions(p, orientation, &node_size));
+                }
+            }
+            let (inv_number, inv_nodes, inv_seq) = inversion_summary(&inversions);
+            result_temp.push(("Inversions (reference)".to_string(), inv_number as f64));
+            result_temp.push(("Inversions nodes (reference)".to_string(), inv_nodes as f64));
+            result_temp.push(("Inversions [bp] (reference)".to_string(), inv_seq as f64));
+        }
+
         // Number of jumps - normalized + bigger than x
         let jumps_total = path_jumps(&path.1);
         result_temp.push(("Jumps total".to_string(), jumps_total as f64));
@@ -97,7 +124,7 @@ pub fn path_stats_wrapper(
             jumps_total as f64 / edges_total_numb,
         ));
 
-        let jumps_bigger_than_x = path_jumps_bigger(&path.1, None);
+        let jumps_bigger_than_x = path_jumps_bigger(&path.1, jump_threshold);
         result_temp.push((
             "Jumps bigger than X".to_string(),
             jumps_bigger_than_x as f64,
@@ -107,6 +134,23 @@ pub fn path_stats_wrapper(
             jumps_bigger_than_x as f64 / edges_total_numb,
         ));
 
+        // Distribution of jumps (node ID and bp)
+        let jump_sizes = path_jump_sizes(&path.1);
+        result_temp.extend(jump_distribution(&jump_sizes, "Jumps", &JUMP_BINS));
+
+        let jump_sizes_bp = path_jump_sizes_bp(&path.1, &node_offset, &node_size);
+        let jumps_total_bp: u64 = jump_sizes_bp.iter().sum();
+        result_temp.push(("Jumps total [bp]".to_string(), jumps_total_bp as f64));
+        result_temp.push((
+            "Jumps total [bp] (normalized)".to_string(),
+            jumps_total_bp as f64 / path_seq,
+        ));
+        result_temp.extend(jump_distribution(
+            &jump_sizes_bp,
+            "Jumps [bp]",
+            &JUMP_BINS_BP,
+        ));
+
         let (node_sizes_avg, node_size_median, node_size_std) = node_size_cal(&path.1, &node_size);
         result_temp.push(("Node size average [bp]".to_string(), node_sizes_avg));
         result_temp.push(("Node size median [bp]".to_string(), node_size_median));
@@ -263,6 +307,24 @@ pub fn path_seq_inverted(
     (inverted, sums)
 }
 
+/// Summary of nodes traversed multiple times by one sample
+///
+/// Return:
+/// - number of nodes with copy number > 1
+/// - sequence of these nodes (counted once)
+/// - maximal copy number
+pub fn path_repeats(copy_number: &Vec<u32>, node_size: &Vec<u32>) -> (usize, usize, u32) {
+    let mut nodes = 0;
+    let mut seq = 0;
+    for (copies, size) in copy_number.iter().zip(node_size.iter()) {
+        if *copies > 1 {
+            nodes += 1;
+            seq += *size as usize;
+        }
+    }
+    (nodes, seq, *copy_number.iter().max().unwrap_or(&0))
+}
+
 /// Calculate the total number of jumps
 ///
 /// Return:
@@ -274,6 +336,9 @@ pub fn path_seq_inverted(
 pub fn path_jumps(path: &Vec<&Path<u32, (), ()>>) -> usize {
     let mut c: usize = 0;
     for p in path.iter() {
+        if p.nodes.is_empty() {
+            continue;
+        }
         let mut last: i64 = p.nodes[0] as i64;
         for node_id in p.nodes.iter().skip(1) {
             c += (*node_id as i64 - last).unsigned_abs() as usize;
@@ -284,12 +349,17 @@ pub fn path_jumps(path: &Vec<&Path<u32, (), ()>>) -> usize {
 }
 
 /// Count the number of jumps bigger than X
+///
+/// X is 20 if no threshold is provided
 pub fn path_jumps_bigger(path: &Vec<&Path<u32, (), ()>>, val: Option<i32>) -> u32 {
     let distance = val.unwrap_or(20);
     let mut c: u32 = 0;
     for p in path.iter() {
-        let mut last = 0;
-        for x in p.nodes.iter() {
+        if p.nodes.is_empty() {
+            continue;
+        }
+        let mut last = p.nodes[0];
+        for x in p.nodes.iter().skip(1) {
             let ff: i32 = *x as i32 - last as i32;
             last = *x;
             if ff.abs() > distance {
@@ -301,6 +371,95 @@ pub fn path_jumps_bigger(path: &Vec<&Path<u32, (), ()>>, val: Option<i32>) -> u3
     c
 }
 
+/// Bins of the jump histogram (node ID, bp)
+pub const JUMP_BINS: [u64; 5] = [1, 10, 100, 1000, 10000];
+pub const JUMP_BINS_BP: [u64; 5] = [100, 1000, 10000, 100000, 1000000];
+
+/// Size of each jump (node ID) along the paths
+pub fn path_jump_sizes(path: &Vec<&Path<u32, (), ()>>) -> Vec<u64> {
+    let mut result = Vec::new();
+    for p in path.iter() {
+        for (n1, n2) in p.nodes.iter().zip(p.nodes.iter().skip(1)) {
+            result.push((*n2 as i64 - *n1 as i64).unsigned_abs());
+        }
+    }
+    result
+}
+
+/// Start position of each node in the (sorted) graph
+///
+/// Sum of the length of all nodes with a smaller ID
+pub fn calc_node_offset(node_size: &Vec<u32>) -> Vec<u64> {
+    let mut result = Vec::with_capacity(node_size.len());
+    let mut pos: u64 = 0;
+    for x in node_size.iter() {
+        result.push(pos);
+        pos += *x as u64;
+    }
+    result
+}
+
+/// Size of each jump along the paths in bp
+///
+/// Amount of graph sequence skipped by a jump (sequence of all nodes between the two nodes).
+/// Two neighbouring nodes (ID 5 -> 6) have a jump of 0 bp.
+pub fn path_jump_sizes_bp(
+    path: &Vec<&Path<u32, (), ()>>,
+    node_offset: &Vec<u64>,
+    node_size: &Vec<u32>,
+) -> Vec<u64> {
+    let mut result = Vec::new();
+    for p in path.iter() {
+        for (n1, n2) in p.nodes.iter().zip(p.nodes.iter().skip(1)) {
+            let (small, big) = (min(*n1, *n2) as usize, max(*n1, *n2) as usize);
+            result.push(
+                node_offset[big].saturating_sub(node_offset[small] + node_size[small] as u64),
+            );
+        }
+    }
+    result
+}
+
+/// Distribution of jump sizes (percentiles + histogram)
+///
+/// Histogram bins are defined by their upper bound, the last bin is open (e.g. 10000-inf)
+pub fn jump_distribution(jumps: &[u64], name: &str, bins: &[u64]) -> Vec<(String, f64)> {
+    let mut sorted = jumps.to_vec();
+    sorted.sort_unstable();
+    let sorted: Vec<f64> = sorted.iter().map(|x| *x as f64).collect();
+
+    let mut result = Vec::new();
+    result.push((name.to_string() + " median", percentile(&sorted, 50.0)));
+    result.push((
+        name.to_string() + " 90th percentile",
+        percentile(&sorted, 90.0),
+    ));
+    result.push((
+        name.to_string() + " 99th percentile",
+        percentile(&sorted, 99.0),
+    ));
+    result.push((name.to_string() + " max", percentile(&sorted, 100.0)));
+
+    let mut counts = vec![0; bins.len() + 1];
+    for x in jumps.iter() {
+        let pos = bins.iter().position(|b| x < b).unwrap_or(bins.len());
+        counts[pos] += 1;
+    }
+    for (i, count) in counts.iter().enumerate() {
+        let lower = if i == 0 { 0 } else { bins[i - 1] };
+        let upper = if i == bins.len() {
+            "inf".to_string()
+        } else {
+            (bins[i] - 1).to_string()
+        };
+        result.push((
+            format!("{} histogram [{}-{}]", name, lower, upper),
+            *count as f64,
+        ));
+    }
+    result
+}
+
 pub fn path_unique2(path: &Vec<&Path<u32, (), ()>>, graph: &Gfa<u32, (), ()>) -> (usize, usize) {
     let mut hp: Vec<u32> = Vec::new();
     for x in path.iter() {
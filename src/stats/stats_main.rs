@@ -1,5 +1,7 @@
+use crate::inversion::inversion_calc::get_reference;
 use crate::stats::graph_stats::graph_stats_wrapper;
 
+use crate::helpers::region::restrict_graph;
 use crate::stats::path_stats::{convert_data, path_stats_wrapper, remove_unsorted};
 use crate::stats::stats_writer::{
     write_tsv_graph, write_tsv_path, write_yaml_graph, write_yaml_path,
@@ -11,7 +13,7 @@ use log::info;
 /// Main function for stats subcommand
 ///
 /// This command should return statistics for total graph or path + write everything to a file
-pub fn stats_main(matches: &ArgMatches) {
+pub fn stats_main(matches: &ArgMatches, regions: &[(String, u64, u64)], output: &str) {
     info!("Running 'gretl stats'");
     let mut sep = " ";
     if matches.is_present("PanSN") {
@@ -27,13 +29,15 @@ pub fn stats_main(matches: &ArgMatches) {
         info!("Reading graph");
         let mut graph: Gfa<u32, (), ()> = Gfa::parse_gfa_file(matches.value_of("gfa").unwrap());
         graph.walk_to_path(sep);
+        if !regions.is_empty() {
+            restrict_graph(&mut graph, regions);
+        }
         if graph.paths.is_empty() {
             panic!("Error: No path found in graph file")
         }
 
         info!("Creating wrapper");
         let wrapper: Pansn<u32, (), ()> = Pansn::from_graph(&graph.paths, sep);
-        let output = matches.value_of("output").unwrap();
 
         let mut bins: Vec<u32> = vec![1, 50, 100, 1000];
         if matches.is_present("bins") {
@@ -44,9 +48,24 @@ pub fn stats_main(matches: &ArgMatches) {
                 .collect();
         }
 
+        let mut jump_threshold = None;
+        if matches.is_present("jump-threshold") {
+            jump_threshold = Some(
+                matches
+                    .value_of("jump-threshold")
+                    .unwrap()
+                    .parse::<i32>()
+                    .unwrap(),
+            );
+        }
+
+        let reference = matches
+            .value_of("reference")
+            .map(|name| get_reference(&graph, name));
+
         if matches.is_present("path") {
             info!("Calculating path stats");
-            let mut data = path_stats_wrapper(&graph, &wrapper, haplo);
+            let mut data = path_stats_wrapper(&graph, &wrapper, haplo, jump_threshold, reference);
             let mut data = convert_data(&mut data);
             remove_unsorted(&mut data, &graph);
 
@@ -57,7 +76,8 @@ pub fn stats_main(matches: &ArgMatches) {
             }
         } else {
             info!("Calculating graph stats");
-            let data = graph_stats_wrapper(&graph, &wrapper, bins, haplo);
+            let data =
+                graph_stats_wrapper(&graph, &wrapper, bins, haplo, jump_threshold, reference);
 
             info!("Writing to file");
             if matches.is_present("YAML") {
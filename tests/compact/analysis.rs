@@ -165,3 +165,791 @@ fn analysis_find_tg() {
 
     cmd.assert().success();
 }
+
+#[test]
+fn analysis_edge_list_tg() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("analysis.edgelist.txt");
+    cmd.arg("edge-list")
+        .arg("--gfa")
+        .arg("./data/example_data/testGraph_complex.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#");
+
+    cmd.assert().success();
+}
+
+#[test]
+/// Edge depth and similarity in both orientations, unused and missing links
+fn analysis_edge_list_values() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("edges.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAA\nS\t2\tCCC\nS\t3\tGGGG\nS\t4\tT\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t1\t+\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\n\
+P\tA#1#c\t1+,2+,3+\t*\n\
+P\tA#2#c\t1+,3+\t*\n\
+P\tB#1#c\t3-,2-,1-,4+\t*\n",
+    )
+    .unwrap();
+    let output = tmp_dir.path().join("edges.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("edge-list")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#");
+    cmd.assert().success();
+
+    // B traverses 1+2+ and 2+3+ in reverse, 3+4+ is not used, 1-4+ is not a link
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(
+        content,
+        "Edge\tLink\tDepth\tSimilarity\n\
+1-4+\t0\t1\t1\n\
+1+2+\t1\t2\t2\n\
+1+3+\t1\t1\t1\n\
+2+3+\t1\t2\t2\n\
+3+4+\t1\t0\t0\n"
+    );
+    let content = fs::read_to_string(format!("{}.missing.txt", output.to_str().unwrap())).unwrap();
+    assert_eq!(content, "Path\tStep\tEdge\nB#1#c\t3\t1-4+\n");
+}
+
+#[test]
+/// Edge mode of core and ps: edges are classified by the number of samples (paths) traversing them
+fn analysis_core_ps_edge_values() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("edges.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAA\nS\t2\tCCC\nS\t3\tGGGG\nS\t4\tT\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t1\t+\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\n\
+P\tA#1#c\t1+,2+,3+\t*\n\
+P\tA#2#c\t1+,3+\t*\n\
+P\tB#1#c\t3-,2-,1-,4+\t*\n",
+    )
+    .unwrap();
+
+    // Core: 1+3+ (A) and 1-4+ (B) are private, 1+2+ and 2+3+ are shared
+    let output = tmp_dir.path().join("edges.core.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("core")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#")
+        .arg("--stats")
+        .arg("edge");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(content, "Similarity\t#Edge\n0\t0\n1\t2\n2\t2\n");
+    let content = fs::read_to_string(format!("{}.private.txt", output.to_str().unwrap())).unwrap();
+    assert_eq!(content, "Path\t#Edge\nA\t1\nB\t1\n");
+
+    // Path similarity: each path is one sample
+    let output = tmp_dir.path().join("edges.ps.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("ps")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--stats")
+        .arg("edge");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(
+        content,
+        "Accession\tEdge:0\tEdge:1\tEdge:2\n\
+A#1#c\t0\t0\t2\n\
+A#2#c\t0\t1\t0\n\
+B#1#c\t0\t1\t2\n"
+    );
+}
+
+#[test]
+/// Jumps above the threshold with path coordinates of both nodes
+fn analysis_jumps_values() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("jumps.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tA\nS\t2\tCC\nS\t3\tGGG\nS\t4\tTTTT\nS\t5\tAAAAA\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t5\t-\t0M\nL\t5\t-\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\n\
+P\ta\t1+,2+,5-,3+\t*\n\
+P\tb\t1+,2+,3+,4+\t*\n",
+    )
+    .unwrap();
+    let output = tmp_dir.path().join("jumps.bed");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("jumps")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--threshold")
+        .arg("1");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(content, "a\t1\t8\t3\t2\t+\t5\t-\na\t3\t11\t2\t5\t-\t3\t+\n");
+}
+
+#[test]
+/// Node 2 is traversed forward by A and reverse by A and B (bi-oriented)
+fn analysis_orientation_values() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("orientation.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tA\nS\t2\tCC\nS\t3\tGGG\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t1\t+\t2\t-\t0M\nL\t2\t-\t3\t+\t0M\n\
+P\tA#1#c\t1+,2+,3+\t*\n\
+P\tA#2#c\t2-\t*\n\
+P\tB#1#c\t1+,2-,3+\t*\n",
+    )
+    .unwrap();
+
+    // Number of samples for each orientation
+    let output = tmp_dir.path().join("orientation.nodelist.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("node-list")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#")
+        .arg("--feature")
+        .arg("Orientation");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(
+        content,
+        "Nodes\t1\t2\t3\nForward\t2\t1\t2\t\nReverse\t0\t2\t0\t\n"
+    );
+
+    // Graph statistics (tsv)
+    let output = tmp_dir.path().join("orientation.stats.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("stats")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    let rows: Vec<Vec<&str>> = content.lines().map(|x| x.split('\t').collect()).collect();
+    let value = |name: &str| rows[1][rows[0].iter().position(|x| *x == name).unwrap()];
+    assert_eq!(value("Bi-oriented nodes"), "1");
+    assert_eq!(value("Bi-oriented nodes [bp]"), "2");
+
+    // Feature filter
+    let output = tmp_dir.path().join("orientation.feature.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("feature")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#")
+        .arg("--bi-oriented");
+    cmd.assert().success();
+    assert_eq!(fs::read_to_string(&output).unwrap(), "2\n");
+}
+
+#[test]
+/// Sample a replaces reference node 3 (3 bp) with node 5 (5 bp), b is identical to the reference
+fn analysis_reference_values() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("reference.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tA\nS\t2\tCC\nS\t3\tGGG\nS\t4\tTTTT\nS\t5\tAAAAA\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\nL\t2\t+\t5\t+\t0M\nL\t5\t+\t4\t+\t0M\n\
+P\tref\t1+,2+,3+,4+\t*\n\
+P\ta\t1+,2+,5+,4+\t*\n\
+P\tb\t1+,2+,3+,4+\t*\n",
+    )
+    .unwrap();
+    let output = tmp_dir.path().join("reference.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("reference")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--reference")
+        .arg("ref");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    let rows: Vec<&str> = content.lines().skip(1).collect();
+    assert_eq!(
+        rows,
+        vec!["a\t7\t0.7\t5\t3\t3\t1\t1", "b\t10\t1\t0\t0\t4\t0\t0"]
+    );
+    let content = fs::read_to_string(format!("{}.missing.bed", output.to_str().unwrap())).unwrap();
+    assert_eq!(content, "ref\t3\t6\ta\n");
+}
+
+#[test]
+/// Reverse-complemented contigs are not inversions
+fn analysis_inversion_reverse_complement() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("inversion.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAAAAAAAAAA\nS\t2\tCCCCCCCCCC\nS\t3\tGGGGGGGGGG\nS\t4\tTTTTTTTTTT\nS\t5\tACACACACAC\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\nL\t4\t+\t5\t+\t0M\n\
+L\t2\t+\t3\t-\t0M\nL\t3\t-\t4\t+\t0M\n\
+P\tref\t1+,2+,3+,4+,5+\t*\n\
+P\trc\t5-,4-,3-,2-,1-\t*\n\
+P\tinv\t1+,2+,3-,4+,5+\t*\n\
+P\trcinv\t5-,4-,3+,2-,1-\t*\n",
+    )
+    .unwrap();
+    let output = tmp_dir.path().join("inversion.bed");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("inversion")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--reference")
+        .arg("ref");
+    cmd.assert().success();
+
+    let content = fs::read_to_string(&output).unwrap();
+    // Only node 3 is inverted, also in the reverse-complemented contig
+    assert_eq!(
+        content,
+        "inv\t20\t30\tref\t20\t30\t1\t10\nrcinv\t20\t30\tref\t20\t30\t1\t10\n"
+    );
+
+    // Same in the path statistics
+    let output = tmp_dir.path().join("inversion.stats.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("stats")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--path")
+        .arg("--reference")
+        .arg("ref");
+    cmd.assert().success();
+
+    let content = fs::read_to_string(&output).unwrap();
+    let rows: Vec<Vec<&str>> = content.lines().map(|x| x.split('\t').collect()).collect();
+    let column = rows[0]
+        .iter()
+        .position(|x| *x == "Inversions (reference)")
+        .unwrap();
+    let inversions: Vec<(&str, &str)> = rows[1..].iter().map(|x| (x[0], x[column])).collect();
+    assert_eq!(
+        inversions,
+        vec![("ref", "0"), ("rc", "0"), ("inv", "1"), ("rcinv", "1")]
+    );
+}
+
+#[test]
+/// Haplotypes of a diploid sample are not summed up
+fn analysis_copy_number_diploid() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("copy_number.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAAAAAAAAAA\nS\t2\tCCCCCCCCCC\nS\t3\tGGGGGGGGGG\nS\t4\tTTTTTTTTTT\nS\t5\tACACACACAC\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\nL\t4\t+\t5\t+\t0M\nL\t3\t+\t2\t+\t0M\n\
+P\tdip#1#chr1\t1+,2+,3+,4+,5+\t*\n\
+P\tdip#2#chr1\t1+,2+,3+,4+,5+\t*\n\
+P\trep#1#chr1\t1+,2+,3+,2+,3+,4+\t*\n",
+    )
+    .unwrap();
+    let output = tmp_dir.path().join("copy_number.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("copy-number")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#");
+    cmd.assert().success();
+
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(
+        content,
+        "Node\tdip\trep\n1\t1\t1\n2\t1\t2\n3\t1\t2\n4\t1\t1\n5\t1\t0\n"
+    );
+
+    // Only the repeat of 'rep' is a collapsed repeat
+    let output = tmp_dir.path().join("copy_number.stats.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("stats")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#");
+    cmd.assert().success();
+
+    let content = fs::read_to_string(&output).unwrap();
+    let rows: Vec<Vec<&str>> = content.lines().map(|x| x.split('\t').collect()).collect();
+    let value = |name: &str| rows[1][rows[0].iter().position(|x| *x == name).unwrap()];
+    assert_eq!(value("Collapsed repeat nodes"), "2");
+    assert_eq!(value("Collapsed repeat nodes [bp]"), "20");
+    assert_eq!(value("Max copy number"), "2");
+}
+
+#[test]
+/// Degree and depth thresholds are independent
+fn analysis_feature_degree_depth() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("feature.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAAAAAAAAAA\nS\t2\tCCCCCCCCCC\nS\t3\tGGGGGGGGGG\nS\t4\tTTTTTTTTTT\nS\t5\tACACACACAC\n\
+L\t1\t+\t2\t+\t0M\nL\t1\t+\t3\t+\t0M\nL\t2\t+\t4\t+\t0M\nL\t3\t+\t4\t+\t0M\nL\t4\t+\t5\t+\t0M\n\
+P\ta\t1+,2+,4+,5+\t*\n\
+P\tb\t1+,3+,4+,5+\t*\n\
+P\tc\t1+,2+,4+,5+\t*\n",
+    )
+    .unwrap();
+    let run = |args: &[&str]| {
+        let output = tmp_dir.path().join("feature.txt");
+        let mut cmd = Command::cargo_bin("gretl").unwrap();
+        cmd.arg("feature")
+            .arg("--gfa")
+            .arg(gfa.to_str().unwrap())
+            .arg("--output")
+            .arg(output.to_str().unwrap())
+            .args(args);
+        cmd.assert().success();
+        fs::read_to_string(&output).unwrap()
+    };
+    // Node 4 is the only node with more than two edges
+    assert_eq!(run(&["--min-degree", "2"]), "4\n");
+    // Node 3 is the only node traversed by less than two paths
+    assert_eq!(run(&["--max-depth", "2"]), "3\n");
+
+    // Same selection in extract
+    let output = tmp_dir.path().join("feature.extract.gfa");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("extract")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--min-degree")
+        .arg("2");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert!(content.contains("S\t4\t"));
+    assert!(!content.contains("S\t2\t"));
+}
+
+#[test]
+/// Walk fragments are named in sequence coordinates
+fn analysis_extract_walk_offset() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("walk.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.1\n\
+S\t1\tAAAAAAAAAA\nS\t2\tCCCCCCCCCC\nS\t3\tGGGGGGGGGG\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\n\
+P\tref#0#chr1\t1+,2+,3+\t*\n\
+W\tsam\t1\tchr1\t1000\t1030\t>1>2>3\n",
+    )
+    .unwrap();
+    fs::write(tmp_dir.path().join("nodes.txt"), "2\n3\n").unwrap();
+    let output = tmp_dir.path().join("walk.extract.gfa");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("extract")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--nodes")
+        .arg(tmp_dir.path().join("nodes.txt").to_str().unwrap());
+    cmd.assert().success();
+
+    let content = fs::read_to_string(&output).unwrap();
+    assert!(content.contains("P\tref#0#chr1:10-30\t2+,3+\t*\n"));
+    assert!(content.contains("P\tsam#1#chr1:1010-1030\t2+,3+\t*\n"));
+
+    // Malformed walk start
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.1\n\
+S\t1\tAAAAAAAAAA\nS\t2\tCCCCCCCCCC\nS\t3\tGGGGGGGGGG\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\n\
+W\tsam\t1\tchr1\tx\t1030\t>1>2>3\n",
+    )
+    .unwrap();
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("extract")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--nodes")
+        .arg(tmp_dir.path().join("nodes.txt").to_str().unwrap());
+    cmd.assert().failure();
+}
+
+#[test]
+/// Paths which leave the region are split into fragments
+fn analysis_region_path_fragments() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("region.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAAAAAAAAAA\nS\t2\tCCCCCCCCCC\nS\t3\tGGGGGGGGGG\nS\t4\tTTTTTTTTTT\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\nL\t1\t+\t3\t+\t0M\nL\t3\t+\t2\t+\t0M\n\
+P\tref\t1+,2+,3+,4+\t*\n\
+P\talt\t1+,3+,2+\t*\n",
+    )
+    .unwrap();
+    let output = tmp_dir.path().join("region.stats.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("stats")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--path")
+        .arg("--region")
+        .arg("ref:0-20");
+    cmd.assert().success();
+
+    let content = fs::read_to_string(&output).unwrap();
+    let rows: Vec<Vec<&str>> = content.lines().map(|x| x.split('\t').collect()).collect();
+    let paths: Vec<(&str, &str)> = rows[1..].iter().map(|x| (x[0], x[1])).collect();
+    // Node 3 is not in the region, both fragments of 'alt' belong to the same path
+    assert_eq!(paths, vec![("ref", "20"), ("alt", "20")]);
+
+    // Still two samples, all nodes are core
+    let output = tmp_dir.path().join("region.core.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("core")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--region")
+        .arg("ref:0-20");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(
+        content,
+        "Similarity\tSequence[bp]\t#Node\n0\t0\t0\n1\t0\t0\n2\t20\t2\n"
+    );
+
+    // Window coordinates on the original path
+    let output = tmp_dir.path().join("region.window.bed");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bed")
+        .arg("-w")
+        .arg("10")
+        .arg("--region")
+        .arg("ref:0-20");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    let windows: Vec<(&str, &str, &str)> = content
+        .lines()
+        .skip(1)
+        .map(|x| {
+            let f: Vec<&str> = x.split('\t').collect();
+            (f[0], f[1], f[2])
+        })
+        .collect();
+    assert_eq!(
+        windows,
+        vec![
+            ("ref", "0", "10"),
+            ("ref", "10", "20"),
+            ("alt", "0", "10"),
+            ("alt", "20", "30")
+        ]
+    );
+}
+
+#[test]
+/// Tables of multiple regions are merged, meta files are kept for each region
+fn analysis_region_merge() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("merge.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAAAAAAAAAA\nS\t2\tCCCCCCCCCC\nS\t3\tGGGGGGGGGG\nS\t4\tTTTTTTTTTT\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\nL\t1\t+\t3\t+\t0M\n\
+P\ta\t1+,2+,3+,4+\t*\n\
+P\tb\t1+,3+,4+\t*\n\
+P\tc\t1+,2+,3+,4+\t*\n",
+    )
+    .unwrap();
+
+    // Graph statistics (tsv)
+    let output = tmp_dir.path().join("merge.stats.txt");
+    // Files of the user are not merged or removed
+    let user_file = tmp_dir.path().join("merge.stats.txt.region0");
+    fs::write(&user_file, "user\n").unwrap();
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("stats")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--region")
+        .arg("a:0-20,a:20-40");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    let rows: Vec<&str> = content.lines().collect();
+    assert_eq!(rows.len(), 3);
+    assert!(rows[0].starts_with("Region\tPaths\t"));
+    assert!(rows[1].starts_with("a:0-20\t3\t"));
+    assert!(rows[2].starts_with("a:20-40\t3\t"));
+    assert_eq!(fs::read_to_string(&user_file).unwrap(), "user\n");
+
+    // YAML can not be merged
+    let output = tmp_dir.path().join("merge.stats.yaml");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("stats")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("-y")
+        .arg("--region")
+        .arg("a:0-20,a:20-40");
+    cmd.assert().failure();
+
+    // Bootstrap
+    let output = tmp_dir.path().join("merge.bootstrap.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--region")
+        .arg("a:0-20,a:20-40");
+    cmd.assert().success();
+    let output = output.to_str().unwrap();
+    for suffix in ["", ".summary.txt", ".heaps.txt", ".combinations.txt"] {
+        let content = fs::read_to_string(format!("{}{}", output, suffix)).unwrap();
+        assert_eq!(content.matches("Region").count(), 1);
+    }
+    assert!(!std::path::Path::new(&format!("{}.meta", output)).exists());
+    for i in 0..2 {
+        let content = fs::read_to_string(format!("{}.region{}.meta", output, i)).unwrap();
+        assert!(content.starts_with("# gretl "));
+    }
+}
+
+#[test]
+/// Window boundaries: the first window exceeding the path is truncated and the last one
+fn analysis_window_boundaries() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("window.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAAAAA\nS\t2\tCCCCC\n\
+L\t1\t+\t2\t+\t0M\n\
+P\ta\t1+,2+\t*\n",
+    )
+    .unwrap();
+    let output = tmp_dir.path().join("window.bed");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bed")
+        .arg("-w")
+        .arg("4")
+        .arg("-m")
+        .arg("3");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    let windows: Vec<(&str, &str)> = content
+        .lines()
+        .skip(1)
+        .map(|x| {
+            let f: Vec<&str> = x.split('\t').collect();
+            (f[1], f[2])
+        })
+        .collect();
+    assert_eq!(
+        windows,
+        vec![("0", "4"), ("3", "7"), ("6", "10"), ("9", "10")]
+    );
+
+    // Same number of windows in the matrix
+    let output = tmp_dir.path().join("window.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("-w")
+        .arg("4")
+        .arg("-m")
+        .arg("3");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(content, "a\t1\t1\t1\t1\n");
+}
+
+#[test]
+/// Aggregations of each window (partial runs at the window borders, truncated last window)
+fn analysis_window_values() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("window.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAAA\nS\t2\tCC\nS\t3\tGGGG\nS\t4\tT\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\nL\t1\t+\t3\t+\t0M\n\
+P\ta\t1+,2+,3+,4+\t*\n\
+P\tb\t1+,3+\t*\n\
+P\tc\t1+,2+\t*\n",
+    )
+    .unwrap();
+    // Similarity per bp: a = 3332222221, b = 3332222, c = 33322
+    let output = tmp_dir.path().join("window.bed");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bed")
+        .arg("-w")
+        .arg("4")
+        .arg("-m")
+        .arg("2")
+        .arg("-a")
+        .arg("mean,median,min,max,fraction")
+        .arg("-t")
+        .arg("2");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(
+        content,
+        "#path\tstart\tend\tnodes\tbp\tsimilarity:mean\tsimilarity:median\tsimilarity:min\tsimilarity:max\tsimilarity:fraction>2\n\
+a\t0\t4\t2\t4\t2.75\t3\t2\t3\t0.75\n\
+a\t2\t6\t3\t4\t2.25\t2\t2\t3\t0.25\n\
+a\t4\t8\t2\t4\t2\t2\t2\t2\t0\n\
+a\t6\t10\t2\t4\t1.75\t2\t1\t2\t0\n\
+a\t8\t10\t2\t2\t1.5\t1.5\t1\t2\t0\n\
+b\t0\t4\t2\t4\t2.75\t3\t2\t3\t0.75\n\
+b\t2\t6\t2\t4\t2.25\t2\t2\t3\t0.25\n\
+b\t4\t7\t1\t3\t2\t2\t2\t2\t0\n\
+c\t0\t4\t2\t4\t2.75\t3\t2\t3\t0.75\n\
+c\t2\t5\t2\t3\t2.3333333333333335\t2\t2\t3\t0.3333333333333333\n"
+    );
+
+    // Node windows (matrix)
+    let output = tmp_dir.path().join("window.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("-n")
+        .arg("-w")
+        .arg("2")
+        .arg("-m")
+        .arg("2")
+        .arg("-a")
+        .arg("median");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(content, "a\t2.5\t1.5\nb\t2.5\tNaN\nc\t2.5\tNaN\n");
+}
+
+#[test]
+/// bedGraph: consecutive nodes with the same value are merged, coordinates of each path
+fn analysis_window_bedgraph_values() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("bedgraph.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tA\nS\t2\tCC\nS\t3\tGGG\nS\t4\tTTTT\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t3\t+\t0M\nL\t3\t+\t4\t+\t0M\nL\t2\t+\t4\t+\t0M\n\
+P\tref\t1+,2+,3+,4+\t*\n\
+P\tb\t1+,2+,4+\t*\n",
+    )
+    .unwrap();
+    let output = tmp_dir.path().join("bedgraph.similarity.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bedgraph")
+        .arg("--metric")
+        .arg("similarity");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(
+        content,
+        "ref\t0\t3\t2\nref\t3\t6\t1\nref\t6\t10\t2\nb\t0\t7\t2\n"
+    );
+
+    // Single path
+    let output = tmp_dir.path().join("bedgraph.nodesize.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bedgraph")
+        .arg("--metric")
+        .arg("nodesize")
+        .arg("--path")
+        .arg("ref");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output).unwrap();
+    assert_eq!(
+        content,
+        "ref\t0\t1\t1\nref\t1\t3\t2\nref\t3\t6\t3\nref\t6\t10\t4\n"
+    );
+}
@@ -62,3 +62,88 @@ fn node_bootstrap4() {
         .arg("data/nodes.txt");
     cmd.assert().success();
 }
+
+#[test]
+/// Depth metrics: levels are traversal counts, core and summary are based on the number of samples
+fn node_bootstrap_depth() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let gfa = tmp_dir.path().join("depth.gfa");
+    fs::write(
+        &gfa,
+        "H\tVN:Z:1.0\n\
+S\t1\tAA\nS\t2\tCCC\nS\t3\tGGGG\n\
+L\t1\t+\t2\t+\t0M\nL\t2\t+\t1\t+\t0M\nL\t1\t+\t3\t+\t0M\n\
+P\tA#1#c\t1+,2+,1+\t*\n\
+P\tB#1#c\t1+,3+\t*\n",
+    )
+    .unwrap();
+    let output = tmp_dir.path().join("depth.bootstrap.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--metric")
+        .arg("depth");
+    cmd.assert().success();
+    let output = output.to_str().unwrap();
+
+    // Node 1 is traversed three times
+    let content = fs::read_to_string(output).unwrap();
+    assert_eq!(
+        content,
+        "Size\tRun\tNode:1\tNode:2\tNode:3\tSeq:1\tSeq:2\tSeq:3\t\n2\t0\t2\t0\t1\t7\t0\t2\n"
+    );
+
+    // Node 1 is in both samples (core)
+    let content = fs::read_to_string(format!("{}.summary.txt", output)).unwrap();
+    let rows: Vec<&str> = content.lines().skip(1).collect();
+    assert_eq!(
+        rows,
+        vec![
+            "2\tPan\t#Node\t1\t3\t3\t0\t3\t3",
+            "2\tCore\t#Node\t1\t1\t1\t0\t1\t1",
+            "2\tLevel:1\t#Node\t1\t2\t2\t0\t2\t2",
+            "2\tLevel:2\t#Node\t1\t1\t1\t0\t1\t1",
+            "2\tPan\t[bp]\t1\t9\t9\t0\t9\t9",
+            "2\tCore\t[bp]\t1\t2\t2\t0\t2\t2",
+            "2\tLevel:1\t[bp]\t1\t7\t7\t0\t7\t7",
+            "2\tLevel:2\t[bp]\t1\t2\t2\t0\t2\t2",
+        ]
+    );
+
+    // Ordered growth
+    let order = tmp_dir.path().join("order.txt");
+    fs::write(&order, "A#1#c\nB#1#c\n").unwrap();
+    let growth = tmp_dir.path().join("depth.growth.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(growth.to_str().unwrap())
+        .arg("--metric")
+        .arg("depth")
+        .arg("--order")
+        .arg(order.to_str().unwrap());
+    cmd.assert().success();
+    let content = fs::read_to_string(&growth).unwrap();
+    assert!(content.ends_with("1\tA#1#c\t1\t2\t5\t2\t5\t2\t5\n2\tB#1#c\t2\t3\t9\t1\t2\t1\t4\n"));
+
+    // Edges have no sequence
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg(gfa.to_str().unwrap())
+        .arg("--output")
+        .arg(output)
+        .arg("--metric")
+        .arg("edge-depth");
+    cmd.assert().success();
+    let content = fs::read_to_string(output).unwrap();
+    assert_eq!(content, "Size\tRun\tEdge:1\t\n2\t0\t3\n");
+    let content = fs::read_to_string(format!("{}.summary.txt", output)).unwrap();
+    assert!(!content.contains("[bp]"));
+    assert!(content.contains("\n2\tCore\t#Edge\t1\t0\t0\t0\t0\t0\n"));
+}
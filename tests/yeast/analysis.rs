@@ -134,6 +134,84 @@ fn analysis_test_sliding_window_2s() {
     cmd.assert().success();
 }
 
+#[test]
+/// Default window output (similarity, mean) is the same as the per-base implementation
+fn analysis_window_per_base() {
+    let gfa = std::fs::read_to_string("./data/example_data/chr5.yeast.gfa").unwrap();
+    let mut node_len: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    let mut paths: Vec<(&str, Vec<&str>)> = Vec::new();
+    for line in gfa.lines() {
+        let fields: Vec<&str> = line.split('\t').collect();
+        match fields[0] {
+            "S" => {
+                node_len.insert(fields[1], fields[2].len());
+            }
+            "P" => paths.push((
+                fields[1],
+                fields[2].split(',').map(|x| &x[..x.len() - 1]).collect(),
+            )),
+            _ => {}
+        }
+    }
+    // Similarity: number of paths which traverse the node
+    let mut similarity: std::collections::HashMap<&str, u32> = std::collections::HashMap::new();
+    for (_, nodes) in paths.iter() {
+        let distinct: std::collections::HashSet<&&str> = nodes.iter().collect();
+        for n in distinct {
+            *similarity.entry(n).or_insert(0) += 1;
+        }
+    }
+
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.window.txt");
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("-w")
+        .arg("1000")
+        .arg("-m")
+        .arg("700");
+    cmd.assert().success();
+    let content = std::fs::read_to_string(&output).unwrap();
+
+    for ((name, nodes), line) in paths.iter().zip(content.lines()) {
+        // Per-base vector and running mean of each window
+        let vector: Vec<u32> = nodes
+            .iter()
+            .flat_map(|n| std::iter::repeat(similarity[n]).take(node_len[n]))
+            .collect();
+        let mut expected: Vec<f64> = Vec::new();
+        let mut start = 0;
+        while start < vector.len() {
+            let end = std::cmp::min(start + 1000, vector.len());
+            let mut mean = 0.0;
+            for (count, value) in vector[start..end].iter().enumerate() {
+                mean += (*value as f64 - mean) / (count as f64 + 1.0);
+            }
+            expected.push(mean);
+            if start + 1000 > vector.len() {
+                break;
+            }
+            start += 700;
+        }
+
+        let fields: Vec<&str> = line.split('\t').collect();
+        assert_eq!(fields[0], *name);
+        let values: Vec<f64> = fields[1..]
+            .iter()
+            .filter(|x| **x != "NaN")
+            .map(|x| x.parse().unwrap())
+            .collect();
+        assert_eq!(values.len(), expected.len());
+        for (value, exp) in values.iter().zip(expected.iter()) {
+            assert!((value - exp).abs() < 1e-9, "{}: {} != {}", name, value, exp);
+        }
+    }
+}
+
 #[test]
 fn analysis_sliding_nwindow_2s_tg() {
     let mut cmd = Command::cargo_bin("gretl").unwrap();
@@ -165,3 +243,390 @@ fn analysis_find_tg() {
     
     cmd.assert().success();
 }
+
+#[test]
+fn analysis_edge_list_tg() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.edgelist.txt");
+    cmd.arg("edge-list")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_test_core_pansn_edge() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.core.pansn.edge.txt");
+    cmd.arg("core")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#")
+        .arg("--stats")
+        .arg("edge");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_ps_edge_tg() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.ps.edge.txt");
+    cmd.arg("ps")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--stats")
+        .arg("edge");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_jumps_tg() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.jumps.bed");
+    cmd.arg("jumps")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--threshold")
+        .arg("100");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_copy_number_tg() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.copynumber.txt");
+    cmd.arg("copy-number")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--pansn")
+        .arg("#");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_inversion_tg() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.inversion.bed");
+    cmd.arg("inversion")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--reference")
+        .arg("ABQ_6.ChrX");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_node_list_orientation_tg() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.nodelist.orientation.txt");
+    cmd.arg("node-list")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--feature")
+        .arg("Orientation");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_reference_tg() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.reference.txt");
+    cmd.arg("reference")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--reference")
+        .arg("ABQ_6.ChrX");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_window_bedgraph() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.window.bedgraph");
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bedgraph")
+        .arg("--metric")
+        .arg("depth")
+        .arg("--path")
+        .arg("ABQ_6.ChrX");
+
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_window_bed() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.window.bed");
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bed")
+        .arg("--window-size")
+        .arg("1000")
+        .arg("--moving-size")
+        .arg("500");
+
+    cmd.assert().success();
+    let content = std::fs::read_to_string(output).unwrap();
+    assert!(content.starts_with("#path\tstart\tend\tnodes\tbp\tsimilarity:mean"));
+    assert!(content.contains("ABQ_6.ChrX\t0\t1000\t"));
+}
+
+#[test]
+fn analysis_window_multi_metric() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.window.multi.bed");
+    cmd.arg("window")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bed")
+        .arg("--metric")
+        .arg("similarity,depth,private")
+        .arg("--aggregation")
+        .arg("mean,median,fraction")
+        .arg("--threshold")
+        .arg("3");
+
+    cmd.assert().success();
+    let content = std::fs::read_to_string(output).unwrap();
+    assert!(content.starts_with("#path\tstart\tend\tnodes\tbp\tsimilarity:mean\tsimilarity:median\tsimilarity:fraction>3\tdepth:mean"));
+    assert!(content.contains("private:fraction>3"));
+}
+
+#[test]
+fn analysis_window_adaptive() {
+    for mode in ["distinct", "graph-bp", "bubble"].iter() {
+        let mut cmd = Command::cargo_bin("gretl").unwrap();
+        let tmp_dir = tempdir().expect("Failed to create temp dir");
+        let output = tmp_dir.path().join("yeast.chr5.window.adaptive.bed");
+        cmd.arg("window")
+            .arg("--gfa")
+            .arg("./data/example_data/chr5.yeast.gfa")
+            .arg("--output")
+            .arg(output.to_str().unwrap())
+            .arg("--bed")
+            .arg("--mode")
+            .arg(mode)
+            .arg("--window-size")
+            .arg("2000");
+
+        cmd.assert().success();
+        let content = std::fs::read_to_string(output).unwrap();
+        assert!(content.contains("ABQ_6.ChrX\t0\t"));
+    }
+}
+
+#[test]
+fn analysis_core_region() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.core.region.txt");
+    cmd.arg("core")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--region")
+        .arg("ABQ_6.ChrX:100000-200000,ABQ_6.ChrX:300000-400000");
+
+    cmd.assert().success();
+    let content = std::fs::read_to_string(&output).unwrap();
+    assert!(content.starts_with("Region\tSimilarity\tSequence[bp]\t#Node"));
+    assert!(content.contains("ABQ_6.ChrX:300000-400000\t5\t"));
+    let private =
+        std::fs::read_to_string(output.to_str().unwrap().to_string() + ".private.txt").unwrap();
+    assert!(private.contains("ABQ_6.ChrX:100000-200000\tABQ_6.ChrX\t"));
+}
+
+#[test]
+/// Subcommands without region support reject the region options
+fn analysis_region_unsupported() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.edges.region.txt");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("edge-list")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--region")
+        .arg("ABQ_6.ChrX:100000-200000");
+
+    cmd.assert().failure();
+    assert!(!output.exists());
+}
+
+#[test]
+fn analysis_extract_region() {
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.extract.gfa");
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("extract")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--region")
+        .arg("ABQ_6.ChrX:100000-200000")
+        .arg("--min-len")
+        .arg("10");
+
+    cmd.assert().success();
+    let content = std::fs::read_to_string(&output).unwrap();
+    assert!(content.contains("\nS\t"));
+    assert!(content.contains("P\tABQ_6.ChrX:"));
+
+    // The subgraph is a valid input again
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    cmd.arg("stats")
+        .arg("--gfa")
+        .arg(output.to_str().unwrap())
+        .arg("--output")
+        .arg(
+            tmp_dir
+                .path()
+                .join("yeast.chr5.extract.stats.txt")
+                .to_str()
+                .unwrap(),
+        );
+    cmd.assert().success();
+}
+
+#[test]
+fn analysis_rarefaction() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.rarefaction.txt");
+    cmd.arg("rarefaction")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap());
+
+    cmd.assert().success();
+    let content = std::fs::read_to_string(&output).unwrap();
+    // All samples: same as core (no sampling)
+    assert!(content.contains("\n5\t26234\t7655\t11264\t7315\t960543\t597805\t98497\t264241\n"));
+    let levels =
+        std::fs::read_to_string(output.to_str().unwrap().to_string() + ".levels.txt").unwrap();
+    assert!(levels.starts_with("Size\tNode:1"));
+    let heaps =
+        std::fs::read_to_string(output.to_str().unwrap().to_string() + ".heaps.txt").unwrap();
+    assert!(heaps.contains("\nPan\t#Node\t"));
+    assert!(heaps.contains("\nCore\t[bp]\t"));
+}
+
+#[test]
+fn analysis_select() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.select.txt");
+    cmd.arg("select")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--coverage")
+        .arg("90")
+        .arg("--required")
+        .arg("BPN_2.ChrX");
+
+    cmd.assert().success();
+    let content = std::fs::read_to_string(&output).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(
+        lines[0],
+        "Step\tSample\tRequired\tNew #Node\tNew [bp]\tCovered #Node\tCovered [bp]\tCoverage"
+    );
+    assert!(lines[1].starts_with("1\tBPN_2.ChrX\tyes\t"));
+    // BPN_2 + ABQ_6 cover 845183 of 960543 bp (< 90%)
+    assert!(lines[2].starts_with("2\tABQ_6.ChrX\tno\t4133\t126143\t19800\t845183\t"));
+    assert!(lines[3].starts_with("3\tBCK_8.ChrX\tno\t"));
+    assert_eq!(lines.len(), 4);
+}
+
+#[test]
+fn analysis_core_classes() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.core.txt");
+    cmd.arg("core")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--classify");
+
+    cmd.assert().success();
+    let output = output.to_str().unwrap().to_string();
+    // Same as the similarity levels (5 samples), soft-core is 4 samples (not the same as core)
+    let classes = std::fs::read_to_string(output.clone() + ".classes.txt").unwrap();
+    assert_eq!(
+        classes,
+        "Class\tMin\tSequence[bp]\t#Node\ncore\t5\t597805\t7655\nsoft-core\t4\t73893\t6833\nshell\t2\t24604\t4431\ncloud\t1\t264241\t7315\n"
+    );
+    let samples = std::fs::read_to_string(output + ".classes.samples.txt").unwrap();
+    assert!(samples.starts_with("Path\tcore[bp]\tsoft-core[bp]\tshell[bp]\tcloud[bp]\tcore#Node"));
+    assert!(samples.contains("\nBIH_4.ChrX\t597805\t73030\t14640\t26389\t7655\t6592\t2517\t278\n"));
+
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let output = tmp_dir.path().join("yeast.chr5.nodelist.txt");
+    cmd.arg("node-list")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("-f")
+        .arg("Class")
+        .arg("--classes")
+        .arg("core:100%,shared:2,private:1");
+    cmd.assert().success();
+    let content = std::fs::read_to_string(&output).unwrap();
+    assert!(content.contains("\nClass\tprivate\tprivate\tprivate\tprivate\tprivate\tshared\t"));
+}
@@ -57,3 +57,227 @@ fn node_bootstrap4() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn node_bootstrap_seed() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempfile::tempdir()?;
+    let mut outputs = Vec::new();
+    for i in 0..2 {
+        let output = tmp_dir
+            .path()
+            .join(format!("yeast.chr5.bootstrap.seed{}.txt", i));
+        let mut cmd = Command::cargo_bin("gretl")?;
+        cmd.arg("bootstrap")
+            .arg("--gfa")
+            .arg("./data/example_data/chr5.yeast.gfa")
+            .arg("--seed")
+            .arg("42")
+            .arg("--output")
+            .arg(output.to_str().unwrap());
+        cmd.assert().success();
+        outputs.push(output);
+    }
+    assert_eq!(
+        fs::read_to_string(&outputs[0])?,
+        fs::read_to_string(&outputs[1])?
+    );
+    let meta = fs::read_to_string(outputs[0].to_str().unwrap().to_string() + ".meta")?;
+    assert!(meta.starts_with("# gretl "));
+    assert!(meta.lines().next().unwrap().ends_with("seed: 42"));
+    let heaps = fs::read_to_string(outputs[0].to_str().unwrap().to_string() + ".heaps.txt")?;
+    assert!(heaps.starts_with("Curve\tUnit\tKappa"));
+
+    Ok(())
+}
+
+#[test]
+fn node_bootstrap_summary() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempfile::tempdir()?;
+    let output = tmp_dir.path().join("yeast.chr5.bootstrap.summary.txt");
+    let mut cmd = Command::cargo_bin("gretl")?;
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap());
+    cmd.assert().success();
+
+    let summary = fs::read_to_string(output.to_str().unwrap().to_string() + ".summary.txt")?;
+    assert!(summary.starts_with("Size\tMeasure\tUnit\tRuns\tMean\tMedian\tStd"));
+    // Only one combination with all genomes
+    assert!(summary.contains("\n5\tPan\t#Node\t1\t26234\t26234\t0\t26234\t26234\n"));
+    assert!(summary.contains("\n3\tLevel:2\t[bp]\t"));
+
+    Ok(())
+}
+
+#[test]
+fn node_bootstrap_exhaustive() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempfile::tempdir()?;
+    let output = tmp_dir.path().join("yeast.chr5.bootstrap.exhaustive.txt");
+    let mut cmd = Command::cargo_bin("gretl")?;
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--number")
+        .arg("3")
+        .arg("--exhaustive")
+        .arg("5");
+    cmd.assert().success();
+
+    // 5 genomes: C(5,2) = C(5,3) = 10 are sampled, C(5,4) = 5 and C(5,5) = 1 are complete
+    let counts = fs::read_to_string(output.to_str().unwrap().to_string() + ".combinations.txt")?;
+    assert_eq!(
+        counts,
+        "Size\tPossible\tUsed\tExhaustive\n2\t10\t3\tno\n3\t10\t3\tno\n4\t5\t5\tyes\n5\t1\t1\tyes\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn node_bootstrap_order() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempfile::tempdir()?;
+    let order = tmp_dir.path().join("order.txt");
+    fs::write(
+        &order,
+        "BCK_8.ChrX\tB\nABQ_6.ChrX\tA\nBIH_4.ChrX\tB\nABF_6.ChrX\nBPN_2.ChrX\tA\n",
+    )?;
+    let output = tmp_dir.path().join("yeast.chr5.growth.txt");
+    let mut cmd = Command::cargo_bin("gretl")?;
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--order")
+        .arg(order.to_str().unwrap());
+    cmd.assert().success();
+
+    let growth = fs::read_to_string(output)?;
+    let lines: Vec<&str> = growth.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[1].starts_with("1\tB\t2\t"));
+    assert!(lines[2].starts_with("2\tA\t4\t"));
+    // All samples: same as core
+    assert_eq!(
+        lines[3],
+        "3\tABF_6.ChrX\t5\t26234\t960543\t7655\t597805\t2180\t33119"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn node_bootstrap_stratified() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempfile::tempdir()?;
+    let groups = tmp_dir.path().join("groups.txt");
+    fs::write(
+        &groups,
+        "ABQ_6.ChrX\tA\nABF_6.ChrX\tA\nBIH_4.ChrX\tB\nBPN_2.ChrX\tB\nBCK_8.ChrX\tB\n",
+    )?;
+    let output = tmp_dir.path().join("yeast.chr5.stratified.txt");
+    let mut cmd = Command::cargo_bin("gretl")?;
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--groups")
+        .arg(groups.to_str().unwrap())
+        .arg("--strata")
+        .arg("equal")
+        .arg("--seed")
+        .arg("3");
+    cmd.assert().success();
+
+    // Equal: 1 or 2 samples from each group -> 2*3 and 1*3 combinations
+    let output = output.to_str().unwrap().to_string();
+    let counts = fs::read_to_string(output.clone() + ".combinations.txt")?;
+    assert_eq!(
+        counts,
+        "Size\tPossible\tUsed\tExhaustive\n2\t6\t6\tyes\n4\t3\t3\tyes\n"
+    );
+    let curves = fs::read_to_string(output + ".groups.txt")?;
+    assert!(curves.starts_with("Group\tSize\tPan #Node\tPan [bp]\tCore #Node\tCore [bp]\n"));
+    assert!(curves.contains("\nA\t2\t"));
+    assert!(curves.contains("\nB\t3\t23651\t879949\t9492\t600509\n"));
+    assert!(curves.contains("\nStratified\t4\t"));
+
+    Ok(())
+}
+
+#[test]
+fn node_bootstrap_subset() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempfile::tempdir()?;
+    let nodes = tmp_dir.path().join("nodes.txt");
+    fs::write(&nodes, "1\n")?;
+    let output = tmp_dir.path().join("yeast.chr5.nodes.txt");
+    let mut cmd = Command::cargo_bin("gretl")?;
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--nodes")
+        .arg(nodes.to_str().unwrap());
+    cmd.assert().success();
+
+    // Node 1 (21176 bp) is private
+    let content = fs::read_to_string(&output)?;
+    assert!(content.contains("\n5\t0\t1\t0\t0\t0\t0\t21176\t0\t0\t0\t0\n"));
+
+    // Same nodes as 'gretl core --region'
+    let bed = tmp_dir.path().join("region.bed");
+    fs::write(&bed, "ABQ_6.ChrX\t100000\t200000\n")?;
+    let mut cmd = Command::cargo_bin("gretl")?;
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bed")
+        .arg(bed.to_str().unwrap());
+    cmd.assert().success();
+    let content = fs::read_to_string(&output)?;
+    assert!(content.contains("\n5\t0\t35\t93\t253\t1060\t1390\t6667\t225\t527\t2786\t95119\n"));
+
+    Ok(())
+}
+
+#[test]
+fn node_bootstrap_metric() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = tempfile::tempdir()?;
+    let output = tmp_dir.path().join("yeast.chr5.edge.txt");
+    let mut cmd = Command::cargo_bin("gretl")?;
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--metric")
+        .arg("edge");
+    cmd.assert().success();
+
+    // Same as 'gretl core -s edge'
+    let content = fs::read_to_string(&output)?;
+    assert!(content.contains("\n5\t0\t15088\t4081\t4036\t12160\t5\n"));
+
+    let mut cmd = Command::cargo_bin("gretl")?;
+    cmd.arg("bootstrap")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--metric")
+        .arg("depth")
+        .arg("--level")
+        .arg("5");
+    cmd.assert().success();
+    let content = fs::read_to_string(&output)?;
+    assert!(content.contains("\n5\t0\t7310\t2192\t2242\t6825\t7648\t17\t"));
+
+    Ok(())
+}
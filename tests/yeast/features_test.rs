@@ -37,3 +37,20 @@ fn stats_graph_path() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn stats_graph_feature_bi_oriented() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("gretl")?;
+    let tmp_dir = tempfile::tempdir()?;
+    let output = tmp_dir.path().join("yeast.chr5node.bi.txt");
+    cmd.arg("feature")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--output")
+        .arg(output.to_str().unwrap())
+        .arg("--bi-oriented");
+
+    cmd.assert().success();
+
+    Ok(())
+}
@@ -96,3 +96,52 @@ fn stats_path_yaml() {
         content
     };
 }
+
+#[test]
+fn stats_graph_jump_threshold() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.graph.jumps.yaml");
+    cmd.arg("stats")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("-y")
+        .arg("--jump-threshold")
+        .arg("100")
+        .arg("--output")
+        .arg(output.to_str().unwrap());
+
+    cmd.assert().success();
+    let content: String = {
+        let mut file = File::open(output.to_str().unwrap()).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        content
+    };
+    assert!(content.contains("Jumps histogram [1-9]"));
+    assert!(content.contains("Jumps total [bp]"));
+}
+
+#[test]
+fn stats_path_reference() {
+    let mut cmd = Command::cargo_bin("gretl").unwrap();
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let output = tmp_dir.path().join("yeast.chr5.path.reference.tsv");
+    cmd.arg("stats")
+        .arg("--gfa")
+        .arg("./data/example_data/chr5.yeast.gfa")
+        .arg("--path")
+        .arg("--reference")
+        .arg("ABQ_6.ChrX")
+        .arg("--output")
+        .arg(output.to_str().unwrap());
+
+    cmd.assert().success();
+    let content: String = {
+        let mut file = File::open(output.to_str().unwrap()).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        content
+    };
+    assert!(content.contains("Inversions (reference)"));
+}